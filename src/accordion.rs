@@ -1,10 +1,14 @@
 use crate::assets::accordion_styles::{ACCORDION_NEW_STYLES, ACCORDION_STYLES};
 use crate::enums::accordion_enums::{AccordionCustomProps, AccordionProps};
+use crate::enums::color_enums::Color;
+use crate::styles::StyleOnce;
+use crate::theme::use_theme;
+use crate::unique_id::use_unique_id;
 use crate::AccordionLeftProps;
 use dioxus::prelude::*;
 
 /// Standard accordion usage example:
-/// ```rust
+/// ```rust,ignore
 ///            Accordion {
 ///                 title: title_one.clone(),
 ///                 accordion_text: first_text.clone(),
@@ -24,16 +28,18 @@ use dioxus::prelude::*;
 /// Of course every rust Option can be simply ignored if preferred.
 #[component]
 pub fn Accordion(props: AccordionProps) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
+    let accordion_id = use_unique_id("accordion");
 
     let plus_svg = rsx! {
         svg {
-            class: "icon",
+            class: "freyr-icon",
             xmlns: "http://www.w3.org/2000/svg",
             fill: "none",
             view_box: "0 0 24 24",
             stroke_width: "2",
-            stroke: "{props.icon_color.as_css_class()}",
+            stroke: "{props.icon_color.to_css_color(&theme)}",
             width: "24",
             height: "24",
             path {
@@ -46,12 +52,12 @@ pub fn Accordion(props: AccordionProps) -> Element {
 
     let minus_svg = rsx! {
         svg {
-            class: "icon",
+            class: "freyr-icon",
             xmlns: "http://www.w3.org/2000/svg",
             fill: "none",
             view_box: "0 0 24 24",
             stroke_width: "2",
-            stroke: "{props.icon_color.as_css_class()}",
+            stroke: "{props.icon_color.to_css_color(&theme)}",
             width: "24",
             height: "24",
             path {
@@ -65,44 +71,49 @@ pub fn Accordion(props: AccordionProps) -> Element {
     let accordion_class = props
         .class
         .clone()
-        .unwrap_or_else(|| String::from("accordion"));
+        .unwrap_or_else(|| String::from("freyr-accordion"));
     let accordion_title_class = props
         .title_class
         .clone()
-        .unwrap_or_else(|| String::from("title-wrapper-default"));
+        .unwrap_or_else(|| String::from("freyr-title-wrapper-default"));
     let accordion_wrapper = props
         .accordion_wrapper
         .clone()
-        .unwrap_or_else(|| String::from("accordion-wrapper-default"));
+        .unwrap_or_else(|| String::from("freyr-accordion-wrapper-default"));
     let accordion_content = props
         .accordion_content
         .clone()
-        .unwrap_or_else(|| String::from("accordion-content-default open-default"));
+        .unwrap_or_else(|| String::from("freyr-accordion-content-default open-default"));
 
     rsx! {
         div {
-            style { "{ACCORDION_STYLES}" }
+            StyleOnce { css: ACCORDION_STYLES }
             div { class: "{accordion_class}",
-                div { class: "{accordion_wrapper} accordion-wrapper",
+                div { class: "{accordion_wrapper} freyr-accordion-wrapper",
                     div { class: "{accordion_title_class}",
-                        h1 { class: "", "{props.title}" }
+                        h1 { id: "{accordion_id}-title", class: "", "{props.title}" }
                     }
                     button {
-                        class: "accordion-button",
+                        class: "freyr-accordion-button",
+                        aria_expanded: "{menu_open}",
+                        aria_controls: if menu_open() { Some(format!("{accordion_id}-content")) } else { None },
+                        aria_labelledby: "{accordion_id}-title",
                         onclick: move |_| menu_open.set(!menu_open()),
                         match menu_open() {
                             true => rsx! {
-                                div { class: "icon-wrapper", {minus_svg} }
+                                div { class: "freyr-icon-wrapper", {minus_svg} }
                             },
                             false => rsx! {
-                                div { class: "icon-wrapper", {plus_svg} }
+                                div { class: "freyr-icon-wrapper", {plus_svg} }
                             },
                         }
                     }
                 }
                 match menu_open() {
                     true => rsx! {
-                        div { class: "{accordion_content} accordion-content open",
+                        div {
+                            id: "{accordion_id}-content",
+                            class: "{accordion_content} freyr-accordion-content open",
                             p { {props.accordion_text} }
                             p { {props.optional_text} }
                         }
@@ -115,7 +126,7 @@ pub fn Accordion(props: AccordionProps) -> Element {
 }
 
 /// Accordion Custom content example:
-/// ```rust
+/// ```rust,ignore
 /// AccordionCustom {
 ///                 title: title_two,
 ///                 accordion_text: rsx! {
@@ -139,32 +150,34 @@ pub fn Accordion(props: AccordionProps) -> Element {
 /// ```
 #[component]
 pub fn AccordionCustom(props: AccordionCustomProps) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
+    let accordion_id = use_unique_id("accordion");
     let accordion_class = props
         .class
         .clone()
-        .unwrap_or_else(|| String::from("accordion"));
+        .unwrap_or_else(|| String::from("freyr-accordion"));
     let accordion_title_class = props
         .title_class
         .clone()
-        .unwrap_or_else(|| String::from("title-wrapper-default"));
+        .unwrap_or_else(|| String::from("freyr-title-wrapper-default"));
     let accordion_wrapper = props
         .accordion_wrapper
         .clone()
-        .unwrap_or_else(|| String::from("accordion-wrapper-default"));
+        .unwrap_or_else(|| String::from("freyr-accordion-wrapper-default"));
     let accordion_content = props
         .accordion_content
         .clone()
-        .unwrap_or_else(|| String::from("accordion-content-default open-default"));
+        .unwrap_or_else(|| String::from("freyr-accordion-content-default open-default"));
 
     let plus_svg = rsx! {
         svg {
-            class: "icon",
+            class: "freyr-icon",
             xmlns: "http://www.w3.org/2000/svg",
             fill: "none",
             view_box: "0 0 24 24",
             stroke_width: "2",
-            stroke: props.icon_color.as_css_class(),
+            stroke: "{props.icon_color.to_css_color(&theme)}",
             width: "24",
             height: "24",
             path {
@@ -177,12 +190,12 @@ pub fn AccordionCustom(props: AccordionCustomProps) -> Element {
 
     let minus_svg = rsx! {
         svg {
-            class: "icon",
+            class: "freyr-icon",
             xmlns: "http://www.w3.org/2000/svg",
             fill: "none",
             view_box: "0 0 24 24",
             stroke_width: "2",
-            stroke: props.icon_color.as_css_class(),
+            stroke: "{props.icon_color.to_css_color(&theme)}",
             width: "24",
             height: "24",
             path {
@@ -195,28 +208,35 @@ pub fn AccordionCustom(props: AccordionCustomProps) -> Element {
 
     rsx! {
         div {
-            style { "{ACCORDION_STYLES}" }
+            StyleOnce { css: ACCORDION_STYLES }
             div { class: "{accordion_class}",
-                div { class: "{accordion_wrapper} accordion-wrapper",
+                div { class: "{accordion_wrapper} freyr-accordion-wrapper",
                     div { class: "{accordion_title_class}",
-                        h1 { "{props.title}" }
+                        h1 { id: "{accordion_id}-title", "{props.title}" }
                     }
                     button {
-                        class: "accordion-button",
+                        class: "freyr-accordion-button",
+                        aria_expanded: "{menu_open}",
+                        aria_controls: if menu_open() { Some(format!("{accordion_id}-content")) } else { None },
+                        aria_labelledby: "{accordion_id}-title",
                         onclick: move |_| menu_open.set(!menu_open()),
                         match menu_open() {
                             true => rsx! {
-                                div { class: "icon-wrapper", {minus_svg} }
+                                div { class: "freyr-icon-wrapper", {minus_svg} }
                             },
                             false => rsx! {
-                                div { class: "icon-wrapper", {plus_svg} }
+                                div { class: "freyr-icon-wrapper", {plus_svg} }
                             },
                         }
                     }
                 }
                 match menu_open() {
                     true => rsx! {
-                        div { class: "{accordion_content} accordion-content open", {props.accordion_text} }
+                        div {
+                            id: "{accordion_id}-content",
+                            class: "{accordion_content} freyr-accordion-content open",
+                            {props.accordion_text}
+                        }
                     },
                     false => rsx! {},
                 }
@@ -226,13 +246,13 @@ pub fn AccordionCustom(props: AccordionCustomProps) -> Element {
 }
 
 /// Accordion where the icon and title are placed on the left:
-/// ```rust
+/// ```rust,ignore
 /// AccordionLeft {
 ///     accordion_width: None,
 ///     // You have to pass colors as you would in a normal CSS file
-///     accordion_header_bg_color: Some("#821e43".to_string()),
+///     accordion_header_bg_color: "#821e43",
 ///     title: "Custom Accordion".to_string(),
-///     title_color: Some(String::from("#ede9df")),
+///     title_color: "#ede9df",
 ///     icon_color: AccordionIconColor::Light,
 ///     // Doesn't have to be "px", could also be "%", "em" or "rem" values for example
 ///     accordion_open_max_height: Some("600px".to_string()),
@@ -243,49 +263,44 @@ pub fn AccordionCustom(props: AccordionCustomProps) -> Element {
 /// ```
 #[component]
 pub fn AccordionLeft(props: AccordionLeftProps) -> Element {
+    let theme = use_theme();
     let mut is_open = use_signal(|| false);
+    let accordion_id = use_unique_id("accordion");
 
+    // Set on the instance itself, so accordions styled differently can be displayed side by side
     let custom_style = format!(
-        r#"
-            :root {{
-                --accordion-width: {};
-                --header-background-color: {};
-                --accordion-open-max-height: {};
-                --box-shadow: {};
-                --border-radius: {};
-                --title-color: {};
-                --title-font-size: {};
-                --title-font-weight: {};
-                --content-padding: {};
-                --icon-color: {};
-            }}
-    "#,
+        "--freyr-accordion-width: {}; --freyr-accordion-header-bg: {}; --freyr-accordion-open-max-height: {}; \
+         --freyr-accordion-shadow: {}; --freyr-accordion-radius: {}; --freyr-accordion-title-color: {}; --freyr-accordion-title-font-size: {}; \
+         --freyr-accordion-title-font-weight: {}; --freyr-accordion-content-padding: {}; --freyr-accordion-icon-color: {};",
         props.accordion_width.clone().unwrap_or("700px".into()),
-        props
-            .accordion_header_bg_color
-            .clone()
-            .unwrap_or("#f0f9ff".into()),
+        props.accordion_header_bg_color.as_ref().map_or_else(
+            || "var(--freyr-color-surface, #FFFFFF)".to_string(),
+            Color::to_string
+        ),
         props
             .accordion_open_max_height
             .clone()
             .unwrap_or("500px".into()),
         props.box_shadow.clone().unwrap_or("".into()),
         props.border_radius.clone().unwrap_or("10px".into()),
-        props.title_color.clone().unwrap_or("#000000".into()),
+        props.title_color.as_ref().map_or_else(
+            || "var(--freyr-color-text, #1E201E)".to_string(),
+            Color::to_string
+        ),
         props.title_font_size.clone().unwrap_or("1.25rem".into()),
         props.title_font_weight.clone().unwrap_or("600".into()),
         props.content_padding.clone().unwrap_or("1rem".into()),
-        props.icon_color.as_css_class(),
+        props.icon_color.to_css_color(&theme),
     );
 
     let chevron_svg = rsx! {
         svg {
-            class: format_args!("accordion-left-icon {}", if is_open() { "open" } else { "" }),
+            class: format_args!("freyr-accordion-left-icon {}", if is_open() { "open" } else { "" }),
             xmlns: "http://www.w3.org/2000/svg",
             fill: "none",
             view_box: "0 0 24 24",
             stroke_width: "2",
-            stroke: "var(--icon-color)",
+            stroke: "var(--freyr-accordion-icon-color)",
             width: "20",
             height: "20",
             path {
@@ -297,21 +312,32 @@ pub fn AccordionLeft(props: AccordionLeftProps) -> Element {
     };
 
     let style_tag = rsx! {
-        style { "{ACCORDION_NEW_STYLES}" }
-        style { "{custom_style}" }
+        StyleOnce { css: ACCORDION_NEW_STYLES }
     };
 
     rsx! {
         div {
             {style_tag}
-            div { class: "accordion-left",
+            div { class: "freyr-accordion-left", style: "{custom_style}",
                 div {
-                    class: "accordion-left-header",
+                    class: "freyr-accordion-left-header",
+                    role: "button",
+                    tabindex: "0",
+                    aria_expanded: "{is_open}",
+                    aria_controls: "{accordion_id}-content",
                     onclick: move |_| is_open.set(!is_open()),
+                    onkeydown: move |event| {
+                        if event.key() == Key::Enter || event.key() == Key::Character(" ".to_string()) {
+                            event.prevent_default();
+                            is_open.set(!is_open());
+                        }
+                    },
                     {chevron_svg}
-                    h2 { class: "accordion-left-title", "{props.title}" }
+                    h2 { class: "freyr-accordion-left-title", "{props.title}" }
                 }
-                div { class: format_args!("accordion-left-content {}", if is_open() { "open" } else { "" }),
+                div {
+                    id: "{accordion_id}-content",
+                    class: format_args!("freyr-accordion-left-content {}", if is_open() { "open" } else { "" }),
                     {props.children}
                 }
             }
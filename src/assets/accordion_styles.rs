@@ -1,10 +1,10 @@
 pub const ACCORDION_STYLES: &str = r#"
-.accordion {
+.freyr-accordion {
     width: 100%;
     max-width: 700px;
 }
 
-.accordion-wrapper {
+.freyr-accordion-wrapper {
     display: flex;
     justify-content: space-between;
     align-items: center;
@@ -14,24 +14,24 @@ pub const ACCORDION_STYLES: &str = r#"
     position: relative;
 }
 
-.accordion-wrapper-default {
+.freyr-accordion-wrapper-default {
     display: flex;
     justify-content: space-between;
     align-items: center;
     width: 28rem;
     padding: 10px;
-    background-color: #6081b9;
+    background-color: var(--freyr-color-primary, #3795BD);
     border-radius: 5px 5px 0 0;
     cursor: pointer;
     position: relative;
 }
 
-.title-wrapper-default {
+.freyr-title-wrapper-default {
     flex-grow: 1;
-    color: #fff;
+    color: var(--freyr-color-on-primary, #FFFFFF);
 }
 
-.accordion-button {
+.freyr-accordion-button {
     display: flex;
     align-items: center;
     background: none;
@@ -39,62 +39,63 @@ pub const ACCORDION_STYLES: &str = r#"
     cursor: pointer;
 }
 
-.icon-wrapper {
+.freyr-icon-wrapper {
     display: flex;
     justify-content: center;
     align-items: center;
 }
 
-.accordion-content-default {
+.freyr-accordion-content-default {
     display: none;
-    background-color: #a2c3db;
+    background-color: var(--freyr-color-primary-muted, #B4C8D1);
     padding: 10px;
-    color: #fff;
+    color: var(--freyr-color-text, #1E201E);
     transition: max-height 0.4s ease-out, padding 0.4s ease-out;
     max-height: 100%;
     overflow: hidden;
 }
 
-.accordion-content-default.open-default {
+.freyr-accordion-content-default.open-default {
     display: block;
     padding: 10px;
     text-align: justify;
 }
 
-.accordion-content {
+.freyr-accordion-content {
     display: none;
     padding: 10px;
     transition: max-height 0.4s ease-out, padding 0.4s ease-out;
     overflow: hidden;
 }
 
-.accordion-content.open {
+.freyr-accordion-content.open {
     display: block;
     padding: 10px;
 }
 
-.icon {
+.freyr-icon {
     width: 24px;
     height: 24px;
     cursor: pointer;
 }
 
 @media (max-width: 768px) {
-    .accordion-wrapper-default {
+    .freyr-accordion-wrapper-default {
         width: 19rem;
     }
+}
 "#;
 
 pub const ACCORDION_NEW_STYLES: &str = r#"
-    .accordion-left {
-        background-color: var(--header-background-color, #f0f9ff);
-        width: var(--accordion-width, 700px);
-        border-radius: var(--border-radius, 10px);
+    .freyr-accordion-left {
+        background-color: var(--freyr-accordion-header-bg, var(--freyr-color-surface, #FFFFFF));
+        width: var(--freyr-accordion-width, 700px);
+        border-radius: var(--freyr-accordion-radius, 10px);
         overflow: hidden;
-        box-shadow: var(--box-shadow, none);
+        box-shadow: var(--freyr-accordion-shadow, none);
     }
    
-    .accordion-left-header {
+    .freyr-accordion-left-header {
         display: flex;
         align-items: center;
         padding: 1rem;
@@ -102,46 +103,46 @@ pub const ACCORDION_NEW_STYLES: &str = r#"
         transition: all 0.3s ease;
     }
     
-    .accordion-left-icon {
+    .freyr-accordion-left-icon {
         margin-right: 1rem;
         transition: transform 0.3s ease;
     }
    
-    .accordion-left-icon.open {
+    .freyr-accordion-left-icon.open {
         transform: rotate(90deg);
     }
    
-    .accordion-left-title {
+    .freyr-accordion-left-title {
         flex-grow: 1;
-        color: var(--title-color, #000000);
-        font-size: var(--title-font-size, 1.25rem);
-        font-weight: var(--title-font-weight, 600);
+        color: var(--freyr-accordion-title-color, var(--freyr-color-text, #1E201E));
+        font-size: var(--freyr-accordion-title-font-size, 1.25rem);
+        font-weight: var(--freyr-accordion-title-font-weight, 600);
         margin: 0;
     }
    
-    .accordion-left-content {
+    .freyr-accordion-left-content {
         max-height: 0;
         overflow: hidden;
-        padding: 0 var(--content-padding, 1rem);
+        padding: 0 var(--freyr-accordion-content-padding, 1rem);
         transition: max-height 0.3s ease, padding 0.3s ease;
     }
    
-    .accordion-left-content.open {
-        max-height: var(--accordion-open-max-height, 500px);
-        padding: var(--content-padding, 1rem);
+    .freyr-accordion-left-content.open {
+        max-height: var(--freyr-accordion-open-max-height, 500px);
+        padding: var(--freyr-accordion-content-padding, 1rem);
     }
    
     @media (max-width: 1000px) {
-        .accordion-left {
+        .freyr-accordion-left {
             width: 100%;
         }
     }
     @media (max-width: 758px) {
-        .accordion-left {
+        .freyr-accordion-left {
             width: 100%;
         }
    
-        .accordion-left-title {
+        .freyr-accordion-left-title {
             font-size: 1rem;
         }
     }
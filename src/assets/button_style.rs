@@ -1,70 +1,98 @@
 pub const BUTTON_STYLES: &str = r#"
-    button {
+    .freyr-btn {
         padding: 10px 20px;
         font-size: 16px;
         border: none;
-        border-radius: 5px;
+        border-radius: var(--freyr-radius-sm, 5px);
+        font-family: var(--freyr-font-family, inherit);
         cursor: pointer;
         transition: background-color 0.3s ease;
         max-width: 300px;
     }
 
-    a {
+    .freyr-btn-link {
         text-decoration: none;
     }
 
-    .btn-freyr {
-        background-color: #3795BD;
-        color: white;
+    .freyr-btn-freyr {
+        background-color: var(--freyr-color-primary, #3795BD);
+        color: var(--freyr-color-on-primary, #FFFFFF);
     }
 
-    .btn-primary {
-        background-color: #007bff;
+    .freyr-btn-primary {
+        background-color: var(--freyr-color-accent, #007BFF);
         color: white;
     }
 
-    .btn-success {
-        background-color: #28a745;
+    .freyr-btn-success {
+        background-color: var(--freyr-color-success, #28A745);
         color: white;
     }
 
-    .btn-danger {
-        background-color: #dc3545;
+    .freyr-btn-danger {
+        background-color: var(--freyr-color-danger, #DC3545);
         color: white;
     }
 
-    .btn-black {
+    .freyr-btn-black {
         background-color: black;
         color: white;
     }
 
-    .btn-transparent {
+    .freyr-btn-transparent {
         background-color: transparent;
         color: black;
         border: 1px solid black;
     }
 
-    button:hover {
+    .freyr-btn:hover {
         filter: brightness(90%);
     }
 
+    .freyr-btn:disabled {
+        opacity: 0.65;
+        cursor: not-allowed;
+        filter: none;
+    }
+
+    .freyr-btn-loading {
+        display: inline-flex;
+        align-items: center;
+        gap: 8px;
+    }
+
+    .freyr-btn-spinner {
+        width: 1em;
+        height: 1em;
+        border: 2px solid currentColor;
+        border-right-color: transparent;
+        border-radius: 50%;
+        animation: freyr-btn-spin 0.75s linear infinite;
+    }
+
+    @keyframes freyr-btn-spin {
+        to {
+            transform: rotate(360deg);
+        }
+    }
+
     /* Media Queries for Responsive Design */
     @media (max-width: 600px) {
-        button {
+        .freyr-btn {
             padding: 12px;
             font-size: 14px;
         }
     }
 
     @media (min-width: 601px) and (max-width: 768px) {
-        button {
+        .freyr-btn {
             padding: 10px 15px;
             font-size: 15px;
         }
     }
 
     @media (min-width: 769px) {
-        button {
+        .freyr-btn {
             width: auto;
             padding: 10px 20px;
             font-size: 16px;
@@ -1,5 +1,5 @@
 pub const CAROUSEL_STYLES: &str = r#"
-.carousel-container {
+.freyr-carousel-container {
     margin: 0 auto;
     position: relative;
     overflow: hidden;
@@ -9,13 +9,13 @@ pub const CAROUSEL_STYLES: &str = r#"
     height: fit-content;
 }
 
-.carousel-container-default {
+.freyr-carousel-container-default {
     width: 40%;
     height: auto;
     max-width: 1200px;
 }
 
-.carousel-simple-dots {
+.freyr-carousel-simple-dots {
     display: flex;
     justify-content: center;
     gap: 8px;
@@ -27,7 +27,7 @@ pub const CAROUSEL_STYLES: &str = r#"
     pointer-events: none;
 }
 
-.carousel-simple-dot {
+.freyr-carousel-simple-dot {
     width: 10px;
     height: 10px;
     background-color: #fff;
@@ -37,27 +37,143 @@ pub const CAROUSEL_STYLES: &str = r#"
     transition: background-color 0.3s ease;
 }
 
-.carousel-simple-dot.active {
+.freyr-carousel-simple-dot.active {
     background-color: #333;
 }
 
-.carousel-content-wrapper {
+.freyr-carousel-content-wrapper {
     position: relative;
     width: 100%;
     height: 100%;
+    touch-action: pan-y;
+    user-select: none;
 }
 
-.carousel {
+.freyr-carousel-container:focus-visible {
+    outline: 2px solid var(--freyr-color-primary, #3795BD);
+    outline-offset: 2px;
+}
+
+.freyr-carousel-arrow {
+    position: absolute;
+    top: 50%;
+    transform: translateY(-50%);
+    z-index: 10;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    width: 36px;
+    height: 36px;
+    padding: 0;
+    border: none;
+    border-radius: 50%;
+    background-color: rgba(255, 255, 255, 0.8);
+    color: #333;
+    cursor: pointer;
+    transition: background-color 0.3s ease;
+}
+
+.freyr-carousel-arrow:hover {
+    background-color: #fff;
+}
+
+.freyr-carousel-arrow:focus-visible {
+    outline: 2px solid var(--freyr-color-primary, #3795BD);
+    outline-offset: 2px;
+}
+
+.freyr-carousel-arrow:disabled {
+    opacity: 0.4;
+    cursor: not-allowed;
+}
+
+.freyr-carousel-empty {
+    margin: 0;
+    padding: 32px 16px;
+    text-align: center;
+    color: #666;
+}
+
+.freyr-carousel-viewport {
+    width: 100%;
+    overflow: hidden;
+}
+
+.freyr-carousel-track {
+    display: flex;
+    transition: transform 0.5s ease-in-out;
+}
+
+.freyr-carousel-slide {
+    flex-shrink: 0;
+    min-width: 0;
+    box-sizing: border-box;
+}
+
+.freyr-carousel-fade {
+    display: grid;
+}
+
+.freyr-carousel-fade > .freyr-carousel-slide {
+    grid-area: 1 / 1;
+    opacity: 0;
+    visibility: hidden;
+    transition: opacity 0.5s ease-in-out, visibility 0.5s;
+}
+
+.freyr-carousel-fade > .freyr-carousel-slide.active {
+    opacity: 1;
+    visibility: visible;
+}
+
+.freyr-carousel-overlay {
+    position: absolute;
+    inset: 0;
+    display: flex;
+    flex-direction: column;
+    align-items: center;
+    justify-content: center;
+    color: #fff;
+}
+
+.freyr-carousel-caption {
+    position: absolute;
+    left: 0;
+    right: 0;
+    bottom: 0;
+    margin: 0;
+    padding: 12px 16px 32px;
+    background: linear-gradient(transparent, rgba(0, 0, 0, 0.6));
+    color: #fff;
+    text-align: center;
+}
+
+@media (prefers-reduced-motion: reduce) {
+    .freyr-carousel-track,
+    .freyr-carousel-fade > .freyr-carousel-slide {
+        transition: none;
+    }
+}
+
+.freyr-carousel-previous {
+    left: 10px;
+}
+
+.freyr-carousel-next {
+    right: 10px;
+}
+
+.freyr-carousel {
     display: flex;
     transition: transform 0.6s ease-in-out;
 }
 
-.carousel-item {
+.freyr-carousel-item {
     min-width: 100%;
     box-sizing: border-box;
 }
 
-.carousel-image {
+.freyr-carousel-image {
     width: 100%;
     height: 100%;
     object-fit: cover;
@@ -65,7 +181,7 @@ pub const CAROUSEL_STYLES: &str = r#"
     display: block;
 }
 
-.carousel-dots {
+.freyr-carousel-dots {
     display: flex;
     justify-content: center;
     gap: 8px;
@@ -77,20 +193,27 @@ pub const CAROUSEL_STYLES: &str = r#"
     pointer-events: none;
 }
 
-.carousel-dot {
+.freyr-carousel-dot {
     width: 10px;
     height: 10px;
+    padding: 0;
+    border: none;
     background-color: #fff;
     border-radius: 50%;
     cursor: pointer;
     pointer-events: auto;
 }
 
-.carousel-dot.active {
+.freyr-carousel-dot:focus-visible {
+    outline: 2px solid var(--freyr-color-primary, #3795BD);
+    outline-offset: 2px;
+}
+
+.freyr-carousel-dot.active {
     background-color: #333;
 }
 
-.carousel-numbers {
+.freyr-carousel-numbers {
     display: flex;
     justify-content: center;
     gap: 8px;
@@ -101,7 +224,7 @@ pub const CAROUSEL_STYLES: &str = r#"
    padding: 8px 0;
 }
 
-.carousel-number {
+.freyr-carousel-number {
     padding: 4px 8px;
     background-color: #ddd;
     border-radius: 4px;
@@ -109,72 +232,72 @@ pub const CAROUSEL_STYLES: &str = r#"
     text-align: center;
 }
 
-.carousel-number.active {
+.freyr-carousel-number.active {
     background-color: #333;
     color: #fff;
 }
 
 @media (max-width: 1024px) {
-    .carousel-container-default {
+    .freyr-carousel-container-default {
         width: 60%;
     }
 
-    .carousel-dots {
+    .freyr-carousel-dots {
         bottom: 8px;
     }
 
-    .carousel-dot {
+    .freyr-carousel-dot {
         width: 10px;
         height: 10px;
     }
 
-    .carousel-numbers {
+    .freyr-carousel-numbers {
         font-size: 0.10rem;
         gap: 6px;
     }
 }
 
 @media (max-width: 768px) {
-    .carousel-image {
+    .freyr-carousel-image {
         object-position: center center;
     }
 
-    .carousel-container-default {
+    .freyr-carousel-container-default {
         width: 70%;
         margin: 0 auto;
     }
 
-    .carousel-dots {
+    .freyr-carousel-dots {
         bottom: 6px;
     }
 
-    .carousel-dot {
+    .freyr-carousel-dot {
         width: 10px;
         height: 10px;
     }
 
-    .carousel-numbers {
+    .freyr-carousel-numbers {
         font-size: 0.8rem;
         gap: 5px;
     }
 
-    .carousel-number {
+    .freyr-carousel-number {
         padding: 2px 4px;
     }
 }
 
 @media (max-width: 480px) {
-    .carousel-container-default {
+    .freyr-carousel-container-default {
         width: 90%;
         height: 50%;
     }
    
-    .carousel-dot {
+    .freyr-carousel-dot {
         width: 10px;
         height: 10px;
     }
 
-    .carousel-numbers {
+    .freyr-carousel-numbers {
         font-size: 0.6rem;
         gap: 4px;
     }
@@ -0,0 +1,137 @@
+pub const DATA_TABLE_STYLES: &str = r#"
+    .freyr-data-table-wrapper {
+        display: flex;
+        flex-direction: column;
+        gap: var(--freyr-spacing-sm, 0.5rem);
+        width: 100%;
+        font-family: var(--freyr-font-family, inherit);
+        color: var(--freyr-color-text, #1E201E);
+    }
+
+    .freyr-data-table-filter {
+        max-width: 320px;
+        padding: 8px 12px;
+        font: inherit;
+        color: inherit;
+        background-color: var(--freyr-color-surface, #fff);
+        border: 1px solid var(--freyr-color-border, #E5E7EB);
+        border-radius: var(--freyr-radius-sm, 5px);
+    }
+
+    .freyr-data-table-filter:focus {
+        outline: 2px solid var(--freyr-color-primary, #3795BD);
+        outline-offset: 1px;
+    }
+
+    .freyr-data-table-scroll {
+        width: 100%;
+        overflow-x: auto;
+        border: 1px solid var(--freyr-color-border, #E5E7EB);
+        border-radius: var(--freyr-radius-md, 8px);
+    }
+
+    .freyr-data-table {
+        width: 100%;
+        border-collapse: collapse;
+        background-color: var(--freyr-color-surface, #fff);
+    }
+
+    .freyr-data-table th,
+    .freyr-data-table td {
+        padding: 10px 14px;
+        text-align: left;
+        border-bottom: 1px solid var(--freyr-color-border, #E5E7EB);
+    }
+
+    .freyr-data-table th {
+        font-weight: var(--freyr-font-weight-bold, 600);
+        white-space: nowrap;
+        background-color: var(--freyr-color-light, #F7F7F8);
+        color: var(--freyr-color-dark, #1E201E);
+    }
+
+    .freyr-data-table tbody tr:last-child td {
+        border-bottom: none;
+    }
+
+    .freyr-data-table tbody tr:hover {
+        background-color: rgba(127, 127, 127, 0.08);
+    }
+
+    .freyr-data-table tbody tr.selected {
+        background-color: var(--freyr-color-primary-muted, #B4C8D1);
+    }
+
+    .freyr-data-table-select {
+        width: 1%;
+    }
+
+    .freyr-data-table-sort {
+        display: inline-flex;
+        align-items: center;
+        gap: 6px;
+        padding: 0;
+        font: inherit;
+        color: inherit;
+        background: none;
+        border: none;
+        cursor: pointer;
+    }
+
+    .freyr-data-table-sort-icon {
+        width: 14px;
+        height: 14px;
+        opacity: 0.4;
+    }
+
+    .freyr-data-table-sort-icon.sorted {
+        opacity: 1;
+    }
+
+    .freyr-data-table-state {
+        padding: 32px 14px;
+        text-align: center;
+        color: var(--freyr-color-muted, #6B7280);
+    }
+
+    .freyr-data-table-loading {
+        display: flex;
+        justify-content: center;
+    }
+
+    .freyr-data-table-pagination {
+        display: flex;
+        align-items: center;
+        justify-content: space-between;
+        gap: var(--freyr-spacing-sm, 0.5rem);
+        font-size: 0.9rem;
+        color: var(--freyr-color-muted, #6B7280);
+    }
+
+    .freyr-data-table-pages {
+        display: flex;
+        align-items: center;
+        gap: var(--freyr-spacing-sm, 0.5rem);
+    }
+
+    .freyr-data-table-page-button {
+        padding: 6px 12px;
+        font: inherit;
+        color: var(--freyr-color-on-primary, #fff);
+        background-color: var(--freyr-color-primary, #3795BD);
+        border: none;
+        border-radius: var(--freyr-radius-sm, 5px);
+        cursor: pointer;
+    }
+
+    .freyr-data-table-page-button:disabled {
+        opacity: 0.5;
+        cursor: not-allowed;
+    }
+
+    @media (max-width: 640px) {
+        .freyr-data-table-pagination {
+            flex-direction: column;
+        }
+    }
+"#;
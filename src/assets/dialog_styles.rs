@@ -1,5 +1,5 @@
 pub const DIALOG_STYLES: &str = r#"
-.dialog-overlay {
+.freyr-dialog-overlay {
     position: fixed;
     inset: 0;
     background-color: rgba(0, 0, 0, 0.5);
@@ -10,17 +10,19 @@ pub const DIALOG_STYLES: &str = r#"
     padding: 0 10px;
 }
 
-.dialog-wrap {
-    background-color: white;
-    border-radius: 0.5rem;
-    box-shadow: 0 10px 25px rgba(0, 0, 0, 0.1);
+.freyr-dialog-wrap {
+    background-color: var(--freyr-color-surface, #FFFFFF);
+    color: var(--freyr-color-text, #1E201E);
+    font-family: var(--freyr-font-family, inherit);
+    border-radius: var(--freyr-radius-md, 8px);
+    box-shadow: var(--freyr-shadow-lg, 0 10px 25px rgba(0, 0, 0, 0.1));
     max-width: 28rem;
     width: 100%;
-    padding: 1.5rem;
+    padding: var(--freyr-spacing-lg, 1.5rem);
     position: relative;
 }
 
-.dialog-close-icon {
+.freyr-dialog-close-icon {
     position: absolute;
     top: 1rem;
     right: 1rem;
@@ -29,38 +31,78 @@ pub const DIALOG_STYLES: &str = r#"
     cursor: pointer;
 }
 
-.dialog-cross-svg {
+.freyr-dialog-cross-svg {
     width: 1.5rem;
     height: 1.5rem;
-    color: #4B5563; /* Tailwind gray-600 */
+    color: var(--freyr-color-muted, #6B7280);
     transition: color 0.2s;
 }
-.dialog-cross-svg:hover {
-    color: #1F2937; /* Tailwind gray-800 */
+.freyr-dialog-cross-svg:hover {
+    color: var(--freyr-color-text, #1E201E);
 }
 
-.dialog-body {
+.freyr-dialog-wrap:focus {
+    outline: none;
+}
+
+.freyr-dialog-title {
+    margin: 0 2rem var(--freyr-spacing-md, 1rem) 0;
+    font-size: 1.25rem;
+    font-weight: var(--freyr-font-weight-bold, 600);
+}
+
+.freyr-dialog-body {
     margin-bottom: 1.5rem;
 }
 
-.dialog-placeholder {
-    color: #6B7280; /* Tailwind gray-500 */
+.freyr-dialog-placeholder {
+    color: var(--freyr-color-muted, #6B7280);
     text-align: center;
 }
 
-.dialog-actions {
+.freyr-dialog-actions {
     display: flex;
     justify-content: flex-end;
+    gap: var(--freyr-spacing-sm, 0.5rem);
+}
+
+.freyr-dialog-open-button {
+    background-color: var(--freyr-color-primary, #3795BD);
+    color: var(--freyr-color-on-primary, #FFFFFF);
+    border: none;
+    padding: var(--freyr-spacing-sm, 0.5rem) var(--freyr-spacing-md, 1rem);
+    border-radius: var(--freyr-radius-sm, 5px);
+    font-family: var(--freyr-font-family, inherit);
+    cursor: pointer;
+    transition: filter 0.2s;
+}
+.freyr-dialog-open-button:hover {
+    filter: brightness(90%);
+}
+
+.freyr-dialog-cancel-button {
+    background-color: transparent;
+    color: var(--freyr-color-text, #1E201E);
+    border: 1px solid var(--freyr-color-border, #E5E7EB);
+    padding: var(--freyr-spacing-sm, 0.5rem) var(--freyr-spacing-md, 1rem);
+    border-radius: var(--freyr-radius-sm, 5px);
+    cursor: pointer;
+    transition: filter 0.2s;
+}
+.freyr-dialog-cancel-button:hover {
+    filter: brightness(90%);
 }
 
-.dialog-close-button {
-    background-color: #4F46E5; /* Tailwind indigo-600 */
-    color: white;
-    padding: 0.5rem 1rem;
-    border-radius: 0.375rem;
-    transition: background-color 0.2s;
-}
-.dialog-close-button:hover {
-    background-color: #4338CA; /* Tailwind indigo-700 */
+.freyr-dialog-close-button {
+    background-color: var(--freyr-color-primary, #3795BD);
+    color: var(--freyr-color-on-primary, #FFFFFF);
+    border: none;
+    padding: var(--freyr-spacing-sm, 0.5rem) var(--freyr-spacing-md, 1rem);
+    border-radius: var(--freyr-radius-sm, 5px);
+    cursor: pointer;
+    transition: filter 0.2s;
+}
+.freyr-dialog-close-button:hover {
+    filter: brightness(90%);
 }
 "#;
\ No newline at end of file
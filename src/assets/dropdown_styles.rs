@@ -1,63 +1,74 @@
 pub const DROPDOWN_STYLES: &str = r#"
-    .dropdown {
+    .freyr-dropdown {
         position: relative;
         display: inline-block;
     }
 
-.dropdown-toggle {
+.freyr-dropdown-toggle {
     color: white;
     padding: 7px 9px;
     border: none;
     cursor: pointer;
-    border-radius: 5px;
+    border-radius: var(--freyr-radius-sm, 5px);
+    font-family: var(--freyr-font-family, inherit);
     transition: background-color 0.3s ease;
     display: flex;
     align-items: center;
     justify-content: space-between;
 }
 
-.dropdown-toggle svg {
+.freyr-dropdown-toggle svg {
     width: 16px;
     height: 16px;
     margin-left: 10px;
 }
 
-    .dropdown-content {
-        position: absolute;
-        box-shadow: 0px 10px 20px rgba(0, 0, 0, 0.1);
+    .freyr-dropdown-content {
+        position: fixed;
+        box-shadow: var(--freyr-shadow-md, 0px 10px 20px rgba(0, 0, 0, 0.1));
         z-index: 1;
-        border-radius: 8px;
+        border-radius: var(--freyr-radius-md, 8px);
         padding: 8px 0;
         width: max-content;
     }
 
-    .link {
+    .freyr-link {
         padding: 10px 13px;
         text-decoration: none;
         display: block;
         transition: background-color 0.3s ease, color 0.3s ease;
 
           &:hover {
-             background: var(--custom_color);
+             background: var(--freyr-dropdown-hover-color);
             }
     }
 
-    .button-config {
+    .freyr-button-config {
         padding: 10px 13px;
         display: block;
         cursor: pointer;
         transition: background-color 0.3s ease, color 0.3s ease;
 
           &:hover {
-             background: var(--custom_color);
+             background: var(--freyr-dropdown-hover-color);
             }
     }
 
-    .dropdown-toggle:hover {
+    .freyr-link.active {
+        font-weight: var(--freyr-font-weight-bold, 600);
+    }
+
+    .freyr-link:focus-visible,
+    .freyr-button-config:focus-visible {
+        outline: none;
+        background: var(--freyr-dropdown-hover-color);
+    }
+
+    .freyr-dropdown-toggle:hover {
         background-color: #2980b9;
     }
     
-    .dropdown-overlay {
+    .freyr-dropdown-overlay {
         position: fixed;
         top: 0;
         left: 0;
@@ -68,24 +79,24 @@ pub const DROPDOWN_STYLES: &str = r#"
     }
 
       @media (max-width: 768px) {
-        .dropdown-toggle {
+        .freyr-dropdown-toggle {
             padding: 8px;
         }
 
-        .dropdown-content {
+        .freyr-dropdown-content {
             border-radius: 2px;
             padding: 0;
         }
 
-        .link {
+        .freyr-link {
             padding: 10px 12px;
         }
 
-        .button-config {
+        .freyr-button-config {
             padding: 10px 12px;
         }
 
-        .dropdown-toggle svg {
+        .freyr-dropdown-toggle svg {
             margin-left: 10px;
         }
     }
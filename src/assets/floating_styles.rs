@@ -0,0 +1,74 @@
+pub const FLOATING_STYLES: &str = r#"
+    .freyr-tooltip-anchor {
+        display: inline-flex;
+    }
+
+    .freyr-tooltip {
+        z-index: 1001;
+        max-width: 260px;
+        padding: 6px 10px;
+        font-family: var(--freyr-font-family, inherit);
+        font-size: 0.85rem;
+        line-height: 1.4;
+        color: var(--freyr-color-light, #F7F7F8);
+        background-color: var(--freyr-color-dark, #1E201E);
+        border-radius: var(--freyr-radius-sm, 5px);
+        box-shadow: var(--freyr-shadow-md, 0px 10px 20px rgba(0, 0, 0, 0.1));
+        overflow-wrap: anywhere;
+    }
+
+    .freyr-popover-trigger {
+        font: inherit;
+        cursor: pointer;
+    }
+
+    .freyr-popover-overlay {
+        position: fixed;
+        inset: 0;
+        z-index: 999;
+        cursor: default;
+    }
+
+    .freyr-popover {
+        z-index: 1000;
+        max-width: min(320px, calc(100vw - 16px));
+        padding: var(--freyr-spacing-md, 1rem);
+        font-family: var(--freyr-font-family, inherit);
+        color: var(--freyr-color-text, #1E201E);
+        background-color: var(--freyr-color-surface, #FFFFFF);
+        border-radius: var(--freyr-radius-md, 8px);
+        box-shadow: var(--freyr-shadow-lg, 0 10px 25px rgba(0, 0, 0, 0.1));
+    }
+
+    .freyr-popover:focus {
+        outline: none;
+    }
+
+    .freyr-floating-arrow {
+        position: absolute;
+        width: 10px;
+        height: 10px;
+        background-color: inherit;
+        pointer-events: none;
+    }
+
+    [data-placement="top"] > .freyr-floating-arrow {
+        bottom: -5px;
+        transform: translateX(-50%) rotate(45deg);
+    }
+
+    [data-placement="bottom"] > .freyr-floating-arrow {
+        top: -5px;
+        transform: translateX(-50%) rotate(45deg);
+    }
+
+    [data-placement="left"] > .freyr-floating-arrow {
+        right: -5px;
+        transform: translateY(-50%) rotate(45deg);
+    }
+
+    [data-placement="right"] > .freyr-floating-arrow {
+        left: -5px;
+        transform: translateY(-50%) rotate(45deg);
+    }
+"#;
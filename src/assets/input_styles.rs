@@ -0,0 +1,162 @@
+pub const INPUT_STYLES: &str = r#"
+    .freyr-form-field {
+        display: flex;
+        flex-direction: column;
+        gap: 6px;
+        margin: 0;
+        padding: 0;
+        border: none;
+        font-family: var(--freyr-font-family, inherit);
+        font-size: var(--freyr-font-size, 1rem);
+        color: var(--freyr-color-text, #1E201E);
+    }
+
+    .freyr-form-field.small {
+        font-size: 0.875rem;
+    }
+
+    .freyr-form-field.large {
+        font-size: 1.125rem;
+    }
+
+    .freyr-form-field-label {
+        padding: 0;
+        font-weight: var(--freyr-font-weight-bold, 600);
+    }
+
+    .freyr-form-field-required {
+        color: var(--freyr-color-danger, #DC3545);
+    }
+
+    .freyr-form-field-control {
+        width: 100%;
+        box-sizing: border-box;
+        padding: 10px 12px;
+        font: inherit;
+        color: inherit;
+        background-color: var(--freyr-color-surface, #fff);
+        border: 1px solid var(--freyr-color-border, #E5E7EB);
+        border-radius: var(--freyr-radius-sm, 5px);
+        transition: border-color 0.3s ease, box-shadow 0.3s ease;
+    }
+
+    .freyr-form-field.small .freyr-form-field-control {
+        padding: 6px 10px;
+    }
+
+    .freyr-form-field.large .freyr-form-field-control {
+        padding: 14px 16px;
+    }
+
+    textarea.freyr-form-field-control {
+        resize: vertical;
+    }
+
+    .freyr-form-field-control:focus {
+        outline: none;
+        border-color: var(--freyr-color-primary, #3795BD);
+        box-shadow: 0 0 0 3px var(--freyr-color-primary-muted, #B4C8D1);
+    }
+
+    .freyr-form-field-control[readonly] {
+        background-color: var(--freyr-color-light, #F7F7F8);
+    }
+
+    .freyr-form-field.invalid .freyr-form-field-control {
+        border-color: var(--freyr-color-danger, #DC3545);
+    }
+
+    .freyr-form-field.disabled {
+        opacity: 0.6;
+    }
+
+    .freyr-form-field.disabled .freyr-form-field-control,
+    .freyr-form-field.disabled .freyr-form-check {
+        cursor: not-allowed;
+    }
+
+    .freyr-form-field-helper,
+    .freyr-form-field-error {
+        margin: 0;
+        font-size: 0.85em;
+    }
+
+    .freyr-form-field-helper {
+        color: var(--freyr-color-muted, #6B7280);
+    }
+
+    .freyr-form-field-error {
+        color: var(--freyr-color-danger, #DC3545);
+    }
+
+    .freyr-form-check {
+        display: inline-flex;
+        align-items: center;
+        gap: 8px;
+        cursor: pointer;
+    }
+
+    .freyr-form-check-input {
+        width: 1.1em;
+        height: 1.1em;
+        margin: 0;
+        accent-color: var(--freyr-color-primary, #3795BD);
+        cursor: inherit;
+    }
+
+    .freyr-form-radio-options {
+        display: flex;
+        flex-direction: column;
+        gap: 6px;
+    }
+
+    .freyr-form-radio-options.inline {
+        flex-direction: row;
+        flex-wrap: wrap;
+        gap: 16px;
+    }
+
+    .freyr-form-switch {
+        position: relative;
+    }
+
+    .freyr-form-switch .freyr-form-check-input {
+        position: absolute;
+        opacity: 0;
+    }
+
+    .freyr-form-switch-track {
+        position: relative;
+        flex-shrink: 0;
+        width: 2.2em;
+        height: 1.25em;
+        border-radius: 999px;
+        background-color: var(--freyr-color-border, #E5E7EB);
+        transition: background-color 0.3s ease;
+    }
+
+    .freyr-form-switch-track::after {
+        content: "";
+        position: absolute;
+        top: 0.125em;
+        left: 0.125em;
+        width: 1em;
+        height: 1em;
+        border-radius: 50%;
+        background-color: #fff;
+        box-shadow: 0 1px 3px rgba(0, 0, 0, 0.3);
+        transition: transform 0.3s ease;
+    }
+
+    .freyr-form-switch .freyr-form-check-input:checked + .freyr-form-switch-track {
+        background-color: var(--freyr-color-primary, #3795BD);
+    }
+
+    .freyr-form-switch .freyr-form-check-input:checked + .freyr-form-switch-track::after {
+        transform: translateX(0.95em);
+    }
+
+    .freyr-form-switch .freyr-form-check-input:focus-visible + .freyr-form-switch-track {
+        box-shadow: 0 0 0 3px var(--freyr-color-primary-muted, #B4C8D1);
+    }
+"#;
@@ -1,4 +1,5 @@
 pub(crate) mod button_style;
+pub(crate) mod input_styles;
 pub(crate) mod navbar_style;
 pub(crate) mod dropdown_styles;
 pub(crate) mod carousel_simple_styles;
@@ -8,3 +9,7 @@ pub(crate) mod navbar_dropdown_styles;
 pub(crate) mod spinner_styles;
 pub(crate) mod dialog_styles;
 pub(crate) mod card_styles;
+pub(crate) mod theme_styles;
+pub(crate) mod data_table_styles;
+pub(crate) mod toast_styles;
+pub(crate) mod floating_styles;
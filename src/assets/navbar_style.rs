@@ -1,10 +1,5 @@
 pub const NAVBAR_STYLES: &str = r#"
-    html, body {
-        margin: 0;
-        padding: 0;
-    }
-
-    .navbar {
+    .freyr-navbar {
         margin: 0;
         display: flex;
         justify-content: space-between;
@@ -14,28 +9,28 @@ pub const NAVBAR_STYLES: &str = r#"
         z-index: 99;
     }
 
-    .nav-div {
+    .freyr-nav-div {
         display: flex;
         align-items: center;
     }
 
-    .nav-header-wrapper {
+    .freyr-nav-header-wrapper {
         font-size: 1.5rem;
         font-weight: bold;
         cursor: pointer;
     }
 
-    .nav-logo {
+    .freyr-nav-logo {
         height: 52px;
         width: 52px;
         cursor: pointer;
     }
 
-    .hamburger {
+    .freyr-hamburger {
         display: none;
     }
 
-    .menu {
+    .freyr-menu {
         display: flex;
         position: static;
         flex-direction: row;
@@ -46,42 +41,153 @@ pub const NAVBAR_STYLES: &str = r#"
         width: 100%;
     }
 
-    .menu-items {
+    .freyr-menu-items {
         display: flex;
         flex-direction: row;
         width: 100%;
     }
 
-    .menu-item {
+    .freyr-menu-item {
         padding: 10px 15px;
         text-decoration: none;
         cursor: pointer;
         transition: color 0.3s ease, background-color 0.3s ease;
     }
    
-    .menu-items.left {
+    .freyr-nav-extra {
+        display: flex;
+        align-items: center;
+        padding: 0 15px;
+    }
+
+    .freyr-nav-extra:empty {
+        display: none;
+    }
+
+    .freyr-navbar-dropdown {
+        position: relative;
+        z-index: 1;
+    }
+
+    .freyr-navbar-dropdown-toggle {
+        display: flex;
+        align-items: center;
+        background: none;
+        border: none;
+        font: inherit;
+        cursor: pointer;
+    }
+
+    .freyr-navbar-dropdown-arrow {
+        width: 16px;
+        height: 16px;
+        margin-left: 6px;
+        transition: transform 0.3s ease;
+    }
+
+    .freyr-navbar-dropdown-arrow.open {
+        transform: rotate(180deg);
+    }
+
+    .freyr-navbar-dropdown-content {
+        position: absolute;
+        top: 100%;
+        left: 0;
+        display: flex;
+        flex-direction: column;
+        width: max-content;
+        min-width: 100%;
+        padding: 8px 0;
+        border-radius: var(--freyr-radius-md, 8px);
+        box-shadow: var(--freyr-shadow-md, 0px 10px 20px rgba(0, 0, 0, 0.1));
+    }
+
+    .freyr-navbar-dropdown-item {
+        padding: 10px 15px;
+        text-decoration: none;
+        transition: background-color 0.3s ease;
+    }
+
+    .freyr-navbar-dropdown-item:hover {
+        background-color: rgba(127, 127, 127, 0.2);
+    }
+
+    .freyr-nav-submenu-overlay {
+        position: fixed;
+        inset: 0;
+    }
+
+    .freyr-navbar-dropdown .freyr-navbar-dropdown-toggle,
+    .freyr-navbar-dropdown .freyr-navbar-dropdown-content {
+        z-index: 1;
+    }
+
+    .freyr-navbar-dropdown .freyr-navbar-dropdown-toggle {
+        position: relative;
+    }
+
+    .freyr-navbar-dropdown-content .freyr-navbar-dropdown-content {
+        position: static;
+        box-shadow: none;
+        padding: 0 0 0 12px;
+    }
+
+    .freyr-navbar-dropdown-item.active {
+        font-weight: var(--freyr-font-weight-bold, 600);
+    }
+
+    .freyr-menu-item.disabled,
+    .freyr-navbar-dropdown-item.disabled {
+        opacity: 0.5;
+        cursor: not-allowed;
+    }
+
+    .freyr-menu-item-icon {
+        display: inline-flex;
+        vertical-align: middle;
+        margin-right: 6px;
+    }
+
+    .freyr-menu-item-badge {
+        display: inline-block;
+        margin-left: 6px;
+        padding: 1px 7px;
+        border-radius: 999px;
+        font-size: 0.75rem;
+        line-height: 1.4;
+        color: var(--freyr-color-on-primary, #fff);
+        background-color: var(--freyr-color-danger, #DC3545);
+    }
+
+    .freyr-menu-items.left {
         justify-content: flex-start;
     }
    
-    .menu-items.center {
+    .freyr-menu-items.center {
         justify-content: center;
     }
    
-    .menu-items.right {
+    .freyr-menu-items.right {
         justify-content: flex-end;
     }
 
-    .menu-item:hover {
+    .freyr-menu-item.active {
+        font-weight: var(--freyr-font-weight-bold, 600);
+        text-decoration: underline;
+        text-underline-offset: 6px;
+    }
+
+    .freyr-menu-item:hover {
         color: #ccc;
         background-color: transparent;
     }
 
     @media (max-width: 600px) {
-        .no-nav-header {
+        .freyr-no-nav-header {
             padding: 16px 25px;
         }
        
-        .hamburger {
+        .freyr-hamburger {
             cursor: pointer;
             display: flex;
             flex-direction: column;
@@ -97,7 +203,7 @@ pub const NAVBAR_STYLES: &str = r#"
             z-index: 20;
         }
 
-        .menu {
+        .freyr-menu {
             display: none;
             flex-direction: column;
             justify-content: center;
@@ -107,36 +213,42 @@ pub const NAVBAR_STYLES: &str = r#"
             top: 0;
             width: 100%;
             height: 100vh;
-            background-color: var(--background-color);
+            background-color: var(--freyr-navbar-background);
             padding: 20px 0;
             z-index: 15;
         }
 
-        .nav-logo {
+        .freyr-nav-logo {
             height: 30px;
             width: 30px;
         }
 
-        .menu.open {
+        .freyr-menu.open {
             display: flex;
-            animation: slideDown 0.4s ease-in-out;
+            animation: freyr-slide-down 0.4s ease-in-out;
         }
 
-        .menu-items {
+        .freyr-menu-items {
             flex-direction: column;
             align-items: center;
         }
 
-        .menu-item {
+        .freyr-navbar-dropdown-content {
+            position: static;
+            align-items: center;
+            box-shadow: none;
+        }
+
+        .freyr-menu-item {
             display: block;
             text-decoration: none;
             transition: color 0.3s ease, background-color 0.3s ease;
-            color: var(--nav-item-color);
+            color: var(--freyr-navbar-item-color);
             padding: 20px;
         }
     }
 
-    @keyframes slideDown {
+    @keyframes freyr-slide-down {
         from {
             transform: translateY(-10%);
             opacity: 0;
@@ -1,9 +1,9 @@
 pub const SPINNER_STYLES: &str = r#"
-        @keyframes spin {
+        @keyframes freyr-spin {
             0% { transform: rotate(0deg); }
             100% { transform: rotate(360deg); }
         }
-        .custom-spinner {
-            animation: spin 1s linear infinite;
+        .freyr-custom-spinner {
+            animation: freyr-spin 1s linear infinite;
         }
     "#;
\ No newline at end of file
@@ -1,57 +1,90 @@
 pub const TABS_STYLES: &str = r#"
-#tabs {
+.freyr-tabs-container {
     display: flex;
     flex-direction: column;
     align-items: center;
 }
 
-.tabs-navigation {
+.freyr-tabs-navigation {
     display: flex;
     justify-content: center;
     gap: 1.5rem;
-    border-bottom: 1px solid #c8ccd2;
+    border-bottom: 1px solid var(--freyr-color-border, #E5E7EB);
     padding-bottom: 4px;
     margin-bottom: 6px;
     flex-wrap: nowrap;
 }
 
-.tab-content {
+.freyr-tab-content {
     // max-width: 60%;
 }
 
-.tab-item {
+.freyr-tab-item {
     cursor: pointer;
-    color: #4b5563;
+    color: var(--freyr-color-muted, #6B7280);
+    font: inherit;
     font-weight: 500;
-    padding-bottom: 2px;
+    padding: 0 0 2px;
+    background: none;
+    border: none;
     border-bottom: 2px solid transparent;
     transition: all 0.3s;
 }
 
-.tab-item.active-tab {
+.freyr-tab-item:disabled,
+.freyr-tab-item:disabled:hover {
+    cursor: not-allowed;
+    opacity: 0.5;
+    color: var(--freyr-color-muted, #6B7280);
+    border-bottom-color: transparent;
+}
+
+.freyr-tab-item:focus-visible {
+    outline: 2px solid currentColor;
+    outline-offset: 4px;
+}
+
+.freyr-tab-content:focus-visible {
+    outline: 2px solid var(--freyr-color-primary, #3795BD);
+    outline-offset: 4px;
+}
+
+.freyr-tab-item.active-tab {
     border-bottom: 2px solid;
     // color: #3b82f6;
 }
 
-.tab-item:hover {
-    border-bottom-color: #3b82f6;
-    color: #3b82f6;
+.freyr-tab-item:hover {
+    border-bottom-color: var(--freyr-color-primary, #3795BD);
+    color: var(--freyr-color-primary, #3795BD);
+}
+
+.freyr-custom-tab-item {
+    color: var(--freyr-tab-custom-color);
+    border-bottom-color: transparent;
 }
 
-.tabs-freyr {
+.freyr-custom-tab-item:hover,
+.freyr-custom-tab-item.active-tab {
+    color: var(--freyr-tab-custom-color);
+    border-bottom-color: var(--freyr-tab-custom-color);
+}
+
+.freyr-tabs-freyr {
     cursor: pointer;
-    color: #3795BD;
+    color: var(--freyr-color-primary, #3795BD);
     padding-bottom: 2px;
     border-bottom: 2px solid transparent;
     transition: all 0.3s;
 }
 
-.tabs-freyr:hover {
-    color: #2E7FA4;
-    border-color: #2E7FA4;
+.freyr-tabs-freyr:hover {
+    color: var(--freyr-color-primary, #3795BD);
+    border-color: var(--freyr-color-primary, #3795BD);
+    filter: brightness(85%);
 }
 
-.tabs-black {
+.freyr-tabs-black {
     cursor: pointer;
     font-weight: 500;
     padding-bottom: 2px;
@@ -60,12 +93,12 @@ pub const TABS_STYLES: &str = r#"
     color: #050505;
 }
 
-.tabs-black:hover {
+.freyr-tabs-black:hover {
     color: #211f1f;
     border-color: #211f1f;
 }
 
-.tabs-light {
+.freyr-tabs-light {
     cursor: pointer;
     font-weight: 500;
     padding-bottom: 2px;
@@ -74,12 +107,12 @@ pub const TABS_STYLES: &str = r#"
     color: #fafafa;
 }
 
-.tabs-light:hover {
+.freyr-tabs-light:hover {
     color: #d8e3e3;
     border-color: #d8e3e3;
 }
 
-@keyframes fade-in {
+@keyframes freyr-fade-in {
     from {
         opacity: 0;
     }
@@ -89,12 +122,12 @@ pub const TABS_STYLES: &str = r#"
 }
 
 @media (max-width: 768px) {
-    .tabs-navigation {
+    .freyr-tabs-navigation {
         flex-direction: row;
         gap: 1rem;
     }
 
-    .tab-content {
+    .freyr-tab-content {
         max-width: 90%;
     }
 
@@ -102,75 +135,86 @@ pub const TABS_STYLES: &str = r#"
 "#;
 
 pub const TABS_SECONDARY_STYLES: &str = r#"
-    .tabs {
+    .freyr-tabs {
       display: flex;
       flex-wrap: wrap;
-      border-radius: var(--tab-radius, 0em 1.5em 1.5em 1.5em);
-      max-width: var(--tab-max-width, 700px);
-      background: var(--tab-header-bg, #e5e5e5);
-      box-shadow: var(--tab-shadow);
+      border-radius: var(--freyr-tab-radius, 0em 1.5em 1.5em 1.5em);
+      max-width: var(--freyr-tab-max-width, 700px);
+      background: var(--freyr-tab-header-bg, var(--freyr-color-border, #E5E7EB));
+      box-shadow: var(--freyr-tab-shadow);
       overflow: hidden;
     }
 
-    .input {
-      position: absolute;
-      opacity: 0;
+    .freyr-tabs-list {
+      display: flex;
+      flex-wrap: wrap;
+      width: 100%;
     }
 
-    .label {
+    .freyr-label {
       width: 100%;
+      border: none;
+      font-family: inherit;
+      text-align: left;
       padding: 15px 25px;
       font-weight: 500;
       cursor: pointer;
       font-size: 18px;
-      background: var(--tab-header-bg, #e5e5e5);
-      color: var(--tab-header-text, #7f7f7f);
+      background: var(--freyr-tab-header-bg, var(--freyr-color-border, #E5E7EB));
+      color: var(--freyr-tab-header-text, var(--freyr-color-muted, #6B7280));
       transition: background 0.1s, color 0.1s;
     }
 
-    .label:hover {
-      background: var(--tab-header-hover, #d8d8d8);
+    .freyr-label:hover {
+      background: var(--freyr-tab-header-hover, var(--freyr-color-primary-muted, #B4C8D1));
     }
 
-    .label:active {
-      background: #ccc;
+    .freyr-label:active {
+      background: var(--freyr-tab-header-hover, var(--freyr-color-primary-muted, #B4C8D1));
+    }
+
+    .freyr-label:disabled {
+      cursor: not-allowed;
+      opacity: 0.5;
+      background: var(--freyr-tab-header-bg, var(--freyr-color-border, #E5E7EB));
     }
    
-    .label:first-child {
-      border-top-left-radius: var(--tab-radius, 1.5em 0 0 0);
+    .freyr-label:first-child {
+      border-top-left-radius: var(--freyr-tab-radius, 1.5em 0 0 0);
     }
 
-    .input:focus + .label {
+    .freyr-label:focus-visible {
       z-index: 1;
+      outline: 2px solid var(--freyr-tab-active-text, var(--freyr-color-text, #1E201E));
+      outline-offset: -4px;
     }
 
-    .input:checked + .label {
-      background: var(--tab-active-bg, #fff);
-      color: var(--tab-active-text, #000);
+    .freyr-label[aria-selected="true"] {
+      background: var(--freyr-tab-active-bg, var(--freyr-color-surface, #FFFFFF));
+      color: var(--freyr-tab-active-text, var(--freyr-color-text, #1E201E));
     }
 
     @media (min-width: 600px) {
-      .label {
+      .freyr-label {
         width: auto;
       }
     }
 
-    .panel {
+    .freyr-panel {
       flex-basis: 100%;
       width: 100%;
       order: 99;
-      display: none;
       padding: 20px 30px 30px;
-      background: var(--tab-active-bg, #fff);
+      background: var(--freyr-tab-active-bg, var(--freyr-color-surface, #FFFFFF));
     }
 
     @media (min-width: 600px) {
-      .panel {
+      .freyr-panel {
         order: 99;
       }
     }
 
-    .input:checked + .label + .panel {
-      display: block;
+    .freyr-panel[hidden] {
+      display: none;
     }
 "#;
\ No newline at end of file
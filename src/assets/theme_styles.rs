@@ -0,0 +1,22 @@
+pub const THEME_STYLES: &str = r#"
+    .freyr-theme {
+        display: contents;
+    }
+
+    .freyr-color-mode-toggle {
+        display: inline-flex;
+        align-items: center;
+        justify-content: center;
+        padding: 6px;
+        background: none;
+        border: none;
+        border-radius: 50%;
+        color: inherit;
+        cursor: pointer;
+        transition: background-color 0.3s ease;
+    }
+
+    .freyr-color-mode-toggle:hover {
+        background-color: rgba(127, 127, 127, 0.2);
+    }
+"#;
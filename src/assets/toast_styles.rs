@@ -0,0 +1,153 @@
+pub const TOAST_STYLES: &str = r#"
+    .freyr-toast-container {
+        position: fixed;
+        z-index: 1000;
+        display: flex;
+        flex-direction: column;
+        gap: 10px;
+        width: min(360px, calc(100vw - 32px));
+        pointer-events: none;
+    }
+
+    .freyr-toast-top-left {
+        top: 16px;
+        left: 16px;
+    }
+
+    .freyr-toast-top-center {
+        top: 16px;
+        left: 50%;
+        transform: translateX(-50%);
+    }
+
+    .freyr-toast-top-right {
+        top: 16px;
+        right: 16px;
+    }
+
+    .freyr-toast-bottom-left {
+        bottom: 16px;
+        left: 16px;
+    }
+
+    .freyr-toast-bottom-center {
+        bottom: 16px;
+        left: 50%;
+        transform: translateX(-50%);
+    }
+
+    .freyr-toast-bottom-right {
+        bottom: 16px;
+        right: 16px;
+    }
+
+    .freyr-toast {
+        --freyr-toast-color: var(--freyr-color-info, #17A2B8);
+        display: flex;
+        align-items: flex-start;
+        gap: 12px;
+        padding: 12px 14px;
+        font-family: var(--freyr-font-family, inherit);
+        font-size: 0.95rem;
+        color: var(--freyr-color-text, #1E201E);
+        background-color: var(--freyr-color-surface, #FFFFFF);
+        border-left: 4px solid var(--freyr-toast-color);
+        border-radius: var(--freyr-radius-md, 8px);
+        box-shadow: var(--freyr-shadow-lg, 0 10px 25px rgba(0, 0, 0, 0.1));
+        pointer-events: auto;
+        animation: freyr-toast-in 0.25s ease-out;
+    }
+
+    .freyr-toast-success {
+        --freyr-toast-color: var(--freyr-color-success, #28A745);
+    }
+
+    .freyr-toast-error {
+        --freyr-toast-color: var(--freyr-color-danger, #DC3545);
+    }
+
+    .freyr-toast-info {
+        --freyr-toast-color: var(--freyr-color-info, #17A2B8);
+    }
+
+    .freyr-toast-warning {
+        --freyr-toast-color: var(--freyr-color-warning, #FFC107);
+    }
+
+    .freyr-toast-icon {
+        display: inline-flex;
+        align-items: center;
+        justify-content: center;
+        flex-shrink: 0;
+        width: 22px;
+        height: 22px;
+        border-radius: 50%;
+        font-size: 0.8rem;
+        font-weight: var(--freyr-font-weight-bold, 600);
+        color: white;
+        background-color: var(--freyr-toast-color);
+    }
+
+    .freyr-toast-content {
+        flex: 1;
+        min-width: 0;
+    }
+
+    .freyr-toast-title,
+    .freyr-toast-message {
+        margin: 0;
+        overflow-wrap: anywhere;
+    }
+
+    .freyr-toast-title {
+        font-weight: var(--freyr-font-weight-bold, 600);
+        margin-bottom: 2px;
+    }
+
+    .freyr-toast-action {
+        flex-shrink: 0;
+        padding: 2px 8px;
+        font: inherit;
+        font-weight: var(--freyr-font-weight-bold, 600);
+        color: var(--freyr-toast-color);
+        background: none;
+        border: none;
+        border-radius: var(--freyr-radius-sm, 5px);
+        cursor: pointer;
+    }
+
+    .freyr-toast-action:hover {
+        background-color: var(--freyr-color-light, #F7F7F8);
+    }
+
+    .freyr-toast-close {
+        display: inline-flex;
+        flex-shrink: 0;
+        padding: 2px;
+        color: var(--freyr-color-muted, #6B7280);
+        background: none;
+        border: none;
+        cursor: pointer;
+    }
+
+    .freyr-toast-close:hover {
+        color: var(--freyr-color-text, #1E201E);
+    }
+
+    @keyframes freyr-toast-in {
+        from {
+            opacity: 0;
+            transform: translateY(-8px);
+        }
+        to {
+            opacity: 1;
+            transform: translateY(0);
+        }
+    }
+
+    @media (prefers-reduced-motion: reduce) {
+        .freyr-toast {
+            animation: none;
+        }
+    }
+"#;
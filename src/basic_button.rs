@@ -1,5 +1,6 @@
 use crate::assets::button_style::BUTTON_STYLES;
 use crate::enums::basic_button_enums::{ButtonColor, ButtonProps, ButtonUrl};
+use crate::styles::StyleOnce;
 use dioxus::prelude::*;
 
 /// Defines which kind of button you want to use: Freyr, Primary, Success, Danger, Black or Transparent.
@@ -9,8 +10,8 @@ use dioxus::prelude::*;
 ///
 /// Using a "Freyr", and a "Transparent" button:
 ///
-/// ```rust
-/// BasicButton { color: ButtonColor::Primary, label: String::from("Go Home"), link: ButtonUrl { url: "/".to_string() } };
+/// ```rust,ignore
+/// BasicButton { color: ButtonColor::Primary, label: String::from("Go Home"), link: ButtonUrl::new(Route::Home {}) };
 ///
 /// // Here the routing is made optional
 /// BasicButton { color: ButtonColor::Freyr, label: String::from("Hello") }
@@ -20,18 +21,18 @@ use dioxus::prelude::*;
 #[component]
 pub fn BasicButton(color: ButtonColor, label: String, link: Option<ButtonUrl>) -> Element {
     let style_tag = rsx! {
-        style { "{BUTTON_STYLES}" }
+        StyleOnce { css: BUTTON_STYLES }
     };
 
     rsx! {
         div {
             {style_tag}
             if let Some(link) = link {
-                Link { to: "{link.url}",
-                    button { class: "{color.to_css_class()}", "{label}" }
+                Link { to: link.url, class: "freyr-btn-link",
+                    button { class: "freyr-btn {color.to_css_class()}", "{label}" }
                 }
             } else {
-                button { class: "{color.to_css_class()}", "{label}" }
+                button { class: "freyr-btn {color.to_css_class()}", "{label}" }
             }
         }
     }
@@ -39,7 +40,7 @@ pub fn BasicButton(color: ButtonColor, label: String, link: Option<ButtonUrl>) -
 
 /// Usage of the button with an event attached to it:
 ///
-/// ```rust
+/// ```rust,ignore
 /// #[component]
 /// pub fn Hero() -> Element {
 ///     let mut count = use_signal(|| 0);
@@ -60,15 +61,27 @@ pub fn BasicButton(color: ButtonColor, label: String, link: Option<ButtonUrl>) -
 #[component]
 pub fn EventButton(props: ButtonProps) -> Element {
     let style_tag = rsx! {
-        style { "{BUTTON_STYLES}" }
+        StyleOnce { css: BUTTON_STYLES }
     };
 
+    let button_type = props.button_type.unwrap_or_else(|| "button".to_string());
+
     rsx! {
         div {
             {style_tag}
             button {
-                class: "{props.color.to_css_class()}",
-                onclick: move |evt| (props.onclick)(evt),
+                class: if props.loading { "freyr-btn {props.color.to_css_class()} freyr-btn-loading" } else { "freyr-btn {props.color.to_css_class()}" },
+                r#type: "{button_type}",
+                disabled: props.disabled || props.loading,
+                aria_busy: "{props.loading}",
+                onclick: move |evt| {
+                    if let Some(onclick) = props.onclick {
+                        onclick.call(evt);
+                    }
+                },
+                if props.loading {
+                    span { class: "freyr-btn-spinner", aria_hidden: "true" }
+                }
                 {props.label}
             }
         }
@@ -1,8 +1,9 @@
 use crate::assets::card_styles::CARD_STYLES;
+use crate::styles::StyleOnce;
 use dioxus::prelude::*;
 
 /// Code example of the Card component:
-/// ```rust
+/// ```rust,ignore
 /// rsx! {
 ///     div { class: "mt-20 flex w-full px-4 flex-col md:flex-row gap-6",
 ///         FreyrCard {
@@ -44,9 +45,9 @@ pub fn FreyrCard(
     class: Option<String>,
 ) -> Element {
     let base_class = if has_shadow {
-        "card-wrapper card-shadow"
+        "freyr-card-wrapper freyr-card-shadow"
     } else {
-        "card-wrapper"
+        "freyr-card-wrapper"
     };
 
     let class_attr = match class {
@@ -55,7 +56,7 @@ pub fn FreyrCard(
     };
 
     rsx! {
-        style { "{CARD_STYLES}" }
+        StyleOnce { css: CARD_STYLES }
         div {
             class: "{class_attr}",
             {children}
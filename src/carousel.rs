@@ -1,10 +1,262 @@
-#[allow(non_snake_case)]
 use crate::assets::carousel_simple_styles::CAROUSEL_STYLES;
-use crate::enums::carousel_simple_enums::CarouselItem;
-use crate::scripts::carousel_script::CAROUSEL_SCRIPT;
-use dioxus::document::eval;
+use crate::enums::carousel_simple_enums::{
+    CarouselItem, CarouselMode, CarouselProps, CarouselTransition,
+};
+use crate::styles::StyleOnce;
+use crate::time::sleep;
+use dioxus::core::Task;
 use dioxus::prelude::*;
-use serde_json::json;
+use std::time::Duration;
+
+/// Horizontal distance, in pixels, a pointer has to travel to swipe to another slide.
+const SWIPE_THRESHOLD: f64 = 50.0;
+
+/// The current slide of a carousel, with the navigation shared by every carousel: arrows, arrow keys,
+/// swipe and pointer-drag gestures.
+#[derive(Clone, Copy, PartialEq)]
+struct CarouselNav {
+    current: Signal<usize>,
+    count: CopyValue<usize>,
+    mode: CopyValue<CarouselMode>,
+    on_slide_change: CopyValue<Option<EventHandler<usize>>>,
+    drag_start: CopyValue<Option<f64>>,
+    hovered: Signal<bool>,
+    focused: Signal<bool>,
+}
+
+fn use_carousel_nav(
+    count: usize,
+    mode: CarouselMode,
+    on_slide_change: Option<EventHandler<usize>>,
+) -> CarouselNav {
+    let mut nav = use_hook(|| CarouselNav {
+        current: Signal::new(0),
+        count: CopyValue::new(count),
+        mode: CopyValue::new(mode),
+        on_slide_change: CopyValue::new(on_slide_change),
+        drag_start: CopyValue::new(None),
+        hovered: Signal::new(false),
+        focused: Signal::new(false),
+    });
+    nav.count.set(count);
+    nav.mode.set(mode);
+    nav.on_slide_change.set(on_slide_change);
+
+    // `current()` is already clamped to the last slide, this only keeps the index from jumping
+    // back when the slides grow again.
+    use_effect(use_reactive!(|count| {
+        let mut current = nav.current;
+        if *current.peek() >= count {
+            current.set(count.saturating_sub(1));
+        }
+    }));
+    nav
+}
+
+impl CarouselNav {
+    /// The current slide, clamped to the last one when the slides shrink.
+    fn current(&self) -> usize {
+        (self.current)().min(self.count.peek().saturating_sub(1))
+    }
+
+    fn peek_current(&self) -> usize {
+        (*self.current.peek()).min(self.count.peek().saturating_sub(1))
+    }
+
+    /// Whether the carousel is hovered or holds the focus, which pauses its autoplay.
+    fn is_paused(&self) -> bool {
+        (self.hovered)() || (self.focused)()
+    }
+
+    fn has_previous(&self) -> bool {
+        *self.count.read() > 1 && (*self.mode.read() == CarouselMode::Loop || self.current() > 0)
+    }
+
+    fn has_next(&self) -> bool {
+        let count = *self.count.read();
+        count > 1 && (*self.mode.read() == CarouselMode::Loop || self.current() + 1 < count)
+    }
+
+    /// Shows the slide at `index`, calling `on_slide_change` when the slide changes.
+    fn go_to(&mut self, index: usize) {
+        if index >= *self.count.peek() || self.peek_current() == index {
+            return;
+        }
+        self.current.set(index);
+        if let Some(on_slide_change) = *self.on_slide_change.peek() {
+            on_slide_change.call(index);
+        }
+    }
+
+    fn previous(&mut self) {
+        let (current, count) = (self.peek_current(), *self.count.peek());
+        if current > 0 {
+            self.go_to(current - 1);
+        } else if *self.mode.peek() == CarouselMode::Loop && count > 0 {
+            self.go_to(count - 1);
+        }
+    }
+
+    fn next(&mut self) {
+        let (current, count) = (self.peek_current(), *self.count.peek());
+        if current + 1 < count {
+            self.go_to(current + 1);
+        } else if *self.mode.peek() == CarouselMode::Loop {
+            self.go_to(0);
+        }
+    }
+
+    fn on_keydown(&mut self, event: KeyboardEvent) {
+        match event.key() {
+            Key::ArrowLeft => self.previous(),
+            Key::ArrowRight => self.next(),
+            _ => return,
+        }
+        event.prevent_default();
+    }
+
+    fn on_pointer_down(&mut self, event: PointerEvent) {
+        self.drag_start.set(Some(event.client_coordinates().x));
+    }
+
+    /// Ends a swipe or a drag, moving to the next or previous slide when it was long enough.
+    fn on_pointer_up(&mut self, event: PointerEvent) {
+        let Some(start) = self.drag_start.write().take() else {
+            return;
+        };
+        let distance = event.client_coordinates().x - start;
+        if distance <= -SWIPE_THRESHOLD {
+            self.next();
+        } else if distance >= SWIPE_THRESHOLD {
+            self.previous();
+        }
+    }
+}
+
+/// The frame shared by every carousel: handles the keyboard, the gestures, the hover and focus
+/// pausing the autoplay, and renders the previous and next buttons when `arrows` is `true`.
+/// A carousel without slides is `empty`, and shows a message instead of its children.
+#[component]
+fn CarouselFrame(
+    nav: CarouselNav,
+    class: Option<String>,
+    arrows: bool,
+    #[props(default)] autoplay: bool,
+    #[props(default)] empty: bool,
+    children: Element,
+) -> Element {
+    let mut nav = nav;
+    let carousel_class = class.unwrap_or_else(|| "freyr-carousel-container-default".to_string());
+    let live = if autoplay && !nav.is_paused() {
+        "off"
+    } else {
+        "polite"
+    };
+
+    rsx! {
+        StyleOnce { css: CAROUSEL_STYLES }
+
+        div {
+            class: "freyr-carousel-container {carousel_class}",
+            role: "region",
+            aria_roledescription: "carousel",
+            tabindex: "0",
+            onkeydown: move |event| nav.on_keydown(event),
+            onmouseenter: move |_| nav.hovered.set(true),
+            onmouseleave: move |_| nav.hovered.set(false),
+            onfocusin: move |_| nav.focused.set(true),
+            onfocusout: move |_| nav.focused.set(false),
+            div {
+                class: "freyr-carousel-content-wrapper",
+                aria_live: live,
+                onpointerdown: move |event| nav.on_pointer_down(event),
+                onpointerup: move |event| nav.on_pointer_up(event),
+                onpointerleave: move |event| nav.on_pointer_up(event),
+                onpointercancel: move |_| nav.drag_start.set(None),
+                if empty {
+                    p { class: "freyr-carousel-empty", "No slides to display." }
+                } else {
+                    {children}
+                }
+                if arrows && !empty {
+                    button {
+                        class: "freyr-carousel-arrow freyr-carousel-previous",
+                        r#type: "button",
+                        aria_label: "Previous slide",
+                        disabled: !nav.has_previous(),
+                        onclick: move |_| nav.previous(),
+                        svg {
+                            view_box: "0 0 24 24",
+                            width: "20",
+                            height: "20",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            "aria-hidden": "true",
+                            path { d: "M15 18l-6-6 6-6" }
+                        }
+                    }
+                    button {
+                        class: "freyr-carousel-arrow freyr-carousel-next",
+                        r#type: "button",
+                        aria_label: "Next slide",
+                        disabled: !nav.has_next(),
+                        onclick: move |_| nav.next(),
+                        svg {
+                            view_box: "0 0 24 24",
+                            width: "20",
+                            height: "20",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            "aria-hidden": "true",
+                            path { d: "M9 18l6-6-6-6" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The buttons showing each position of a carousel.
+#[component]
+fn CarouselDots(nav: CarouselNav, count: usize) -> Element {
+    let mut nav = nav;
+
+    rsx! {
+        div { class: "freyr-carousel-dots",
+            for index in 0..count {
+                button {
+                    class: if nav.current() == index { "freyr-carousel-dot active" } else { "freyr-carousel-dot" },
+                    r#type: "button",
+                    aria_label: "Go to slide {index + 1}",
+                    aria_current: if nav.current() == index { "true" } else { "false" },
+                    onclick: move |_| nav.go_to(index),
+                }
+            }
+        }
+    }
+}
+
+/// The image of a carousel item, with its overlay and caption.
+#[component]
+fn ImageSlide(item: CarouselItem) -> Element {
+    rsx! {
+        img {
+            src: "{item.image_url}",
+            alt: "{item.image_alt}",
+            class: "freyr-carousel-image",
+            draggable: "false",
+        }
+        if let Some(overlay) = item.overlay {
+            div { class: "freyr-carousel-overlay", {overlay} }
+        }
+        if let Some(caption) = item.caption {
+            p { class: "freyr-carousel-caption", "{caption}" }
+        }
+    }
+}
 
 /// A simple carousel component for Dioxus.
 ///
@@ -15,14 +267,18 @@ use serde_json::json;
 /// - Displays a single image at a time, with navigation dots for switching between images.
 /// - Supports custom CSS classes for styling via the `class` prop.
 /// - Dynamically updates the displayed image when a navigation dot is clicked.
+/// - Every carousel moves between its slides with the Left and Right arrow keys, and with swipe and drag gestures.
 ///
 /// # Props
-/// - `items: Vec<CarouselItem>`: A list of items to display in the carousel, where each item contains an image URL.
+/// - `items: Vec<CarouselItem>`: A list of items to display in the carousel, where each item contains an image URL
+///   and its alternate text. Without items, the carousel shows a "No slides to display." message.
 /// - `class: Option<String>`: An optional CSS class to customize the styling of the carousel.
-/// - `alt: Vec<CarouselItem>`: A list of items containing alternate text for each image.
+/// - `arrows: bool`: Shows previous and next buttons on the sides of the carousel, `false` by default.
+/// - `mode: CarouselMode`: Whether the carousel loops from its last slide to its first one, or stops there.
+/// - `on_slide_change: EventHandler<usize>`: Called with the index of the newly shown slide.
 ///
 /// # Usage
-/// ```rust
+/// ```rust,ignore
 /// use freyr::prelude::*;
 ///
 ///        const BIRD: Asset = asset!("./assets/one.jpg");
@@ -35,13 +291,13 @@ use serde_json::json;
 ///            CarouselItem::new(DOG, String::from("Image 3")),
 ///        ];
 ///
-///     let alt = items.clone(); // Alternate text can be reused if identical.
-///
 /// rsx! {
 ///     CarouselSimple {
 ///         items: items,
-///         alt: alt,
 ///         class: Some(String::from("w-full h-full flex justify-center rounded-lg")),
+///         arrows: true,
+///         mode: CarouselMode::Bounded,
+///         on_slide_change: move |index| tracing::info!("Slide {index}"),
 ///     }
 /// }
 /// ```
@@ -49,32 +305,22 @@ use serde_json::json;
 pub fn CarouselSimple(
     items: Vec<CarouselItem>,
     class: Option<String>,
-    alt: Vec<CarouselItem>,
+    #[props(default)] arrows: bool,
+    #[props(default)] mode: CarouselMode,
+    on_slide_change: Option<EventHandler<usize>>,
 ) -> Element {
-    let mut current_index = use_signal(|| 0);
-
-    let carousel_class = if let Some(custom_class) = class {
-        custom_class
-    } else {
-        "carousel-container-default".to_string()
-    };
+    let mut nav = use_carousel_nav(items.len(), mode, on_slide_change);
 
     rsx! {
-        style { "{CAROUSEL_STYLES}" }
-
-        div { class: "carousel-container {carousel_class}",
-            div { class: "carousel-content-wrapper",
-                img {
-                    src: "{items[current_index()].image_url}",
-                    alt: "{alt[current_index()].image_alt}",
-                    class: "carousel-image",
-                }
-                div { class: "carousel-simple-dots",
-                    for index in 0..items.len() {
-                        div {
-                            class: if current_index() == index { "carousel-simple-dot active" } else { "carousel-simple-dot" },
-                            onclick: move |_| current_index.set(index),
-                        }
+        CarouselFrame { nav, class, arrows, empty: items.is_empty(),
+            if let Some(item) = items.get(nav.current()).cloned() {
+                ImageSlide { item }
+            }
+            div { class: "freyr-carousel-simple-dots",
+                for index in 0..items.len() {
+                    div {
+                        class: if nav.current() == index { "freyr-carousel-simple-dot active" } else { "freyr-carousel-simple-dot" },
+                        onclick: move |_| nav.go_to(index),
                     }
                 }
             }
@@ -83,69 +329,49 @@ pub fn CarouselSimple(
 }
 
 /// Use it like the CarouselSimple component, just add the ```timer_seconds``` prop like that: ```timer_seconds: 5```
+///
+/// Each carousel has its own timer, which pauses while the carousel is hovered or focused,
+/// restarts when the slide changes, and stops when the carousel is removed.
 #[component]
 pub fn CarouselWithTimer(
     items: Vec<CarouselItem>,
     class: Option<String>,
-    alt: Vec<CarouselItem>,
     timer_seconds: u64,
+    #[props(default)] arrows: bool,
+    #[props(default)] mode: CarouselMode,
+    on_slide_change: Option<EventHandler<usize>>,
 ) -> Element {
-    let mut current_index = use_signal(|| 0);
+    let nav = use_carousel_nav(items.len(), mode, on_slide_change);
+    let mut autoplay = use_hook(|| CopyValue::new(None::<Task>));
 
-    let carousel_class = if let Some(custom_class) = class {
-        custom_class
-    } else {
-        "carousel-container-default".to_string()
-    };
+    let items_len = items.len();
 
-    let items_cloned = items.clone();
-
-    let items_data = items_cloned
-        .iter()
-        .map(|item| {
-            json!({
-                "image_url": item.image_url.to_string(),
-                "image_alt": item.image_alt,
-            })
-        })
-        .collect::<Vec<_>>();
-
-    let timer_ms = timer_seconds * 1000;
-
-    use_effect(move || {
-        let items_json = serde_json::to_string(&items_data).unwrap();
-        let script = CAROUSEL_SCRIPT
-            .replace("{initial_index}", &current_index().to_string())
-            .replace("{items_len}", &items_cloned.len().to_string())
-            .replace("{items_data}", &items_json)
-            .replace("{timer_ms}", &timer_ms.to_string());
-        eval(&script);
-    });
+    // Schedules the next slide again whenever the slide changes or the carousel is paused or resumed.
+    // The task belongs to the component, so it is dropped with it.
+    use_effect(use_reactive!(|items_len, timer_seconds| {
+        nav.current();
+        let paused = nav.is_paused();
+        if let Some(task) = autoplay.write().take() {
+            task.cancel();
+        }
+        if paused || items_len < 2 || timer_seconds == 0 {
+            return;
+        }
 
-    rsx! {
-        style { "{CAROUSEL_STYLES}" }
-
-        div { class: "carousel-container {carousel_class}",
-            div { class: "carousel-content-wrapper",
-                img {
-                    id: "carousel-image",
-                    src: "{items[current_index()].image_url}",
-                    alt: "{alt[current_index()].image_alt}",
-                    class: "carousel-image",
-                }
+        autoplay.set(Some(spawn(async move {
+            sleep(Duration::from_secs(timer_seconds)).await;
+            let mut nav = nav;
+            nav.next();
+        })));
+    }));
 
-                div { class: "carousel-dots",
-                    for index in 0..items.len() {
-                        div {
-                            class: if current_index() == index { "carousel-dot active" } else { "carousel-dot" },
-                            onclick: move |_| {
-                                current_index.set(index);
-                                eval(&format!("window.setCarouselIndex({})", index));
-                            },
-                        }
-                    }
-                }
+    rsx! {
+        CarouselFrame { nav, class, arrows, autoplay: true, empty: items.is_empty(),
+            if let Some(item) = items.get(nav.current()).cloned() {
+                ImageSlide { item }
             }
+
+            CarouselDots { nav, count: items_len }
         }
     }
 }
@@ -155,37 +381,114 @@ pub fn CarouselWithTimer(
 pub fn CarouselWithNumbers(
     items: Vec<CarouselItem>,
     class: Option<String>,
-    alt: Vec<CarouselItem>,
+    #[props(default)] arrows: bool,
+    #[props(default)] mode: CarouselMode,
+    on_slide_change: Option<EventHandler<usize>>,
 ) -> Element {
-    let mut current_index = use_signal(|| 0);
-
-    let carousel_class = if let Some(custom_class) = class {
-        custom_class
-    } else {
-        "carousel-container-default".to_string()
-    };
+    let mut nav = use_carousel_nav(items.len(), mode, on_slide_change);
 
     rsx! {
-        style { "{CAROUSEL_STYLES}" }
-
-        div { class: "carousel-container {carousel_class}",
-            div { class: "carousel-content-wrapper",
-                img {
-                    src: "{items[current_index()].image_url}",
-                    alt: "{alt[current_index()].image_alt}",
-                    class: "carousel-image",
+        CarouselFrame { nav, class, arrows, empty: items.is_empty(),
+            if let Some(item) = items.get(nav.current()).cloned() {
+                ImageSlide { item }
+            }
+
+            div { class: "freyr-carousel-numbers",
+                for index in 0..items.len() {
+                    div {
+                        class: if nav.current() == index { "freyr-carousel-number active" } else { "freyr-carousel-number" },
+                        onclick: move |_| nav.go_to(index),
+                        "{index + 1}"
+                    }
                 }
+            }
+        }
+    }
+}
 
-                div { class: "carousel-numbers",
-                    for index in 0..items.len() {
+/// A carousel of any content: images, cards, text or videos, with a slide or fade `transition`.
+///
+/// With the `Slide` transition, `items_per_view` slides are shown side by side, e.g. for product strips,
+/// and the carousel moves one slide at a time. The arrows, the dots, the arrow keys and the swipe
+/// gestures work like in the other carousels.
+///
+/// ```rust,ignore
+/// let products = vec![
+///     rsx! { Card { title: "Lamp", "29 €" } },
+///     rsx! { Card { title: "Chair", "89 €" } },
+///     rsx! { Card { title: "Table", "249 €" } },
+///     rsx! { Card { title: "Shelf", "129 €" } },
+/// ];
+///
+/// rsx! {
+///     Carousel {
+///         slides: products,
+///         items_per_view: 3,
+///         mode: CarouselMode::Bounded,
+///         class: "w-full",
+///     }
+/// }
+/// ```
+#[component]
+pub fn Carousel(props: CarouselProps) -> Element {
+    let CarouselProps {
+        slides,
+        transition,
+        items_per_view,
+        arrows,
+        dots,
+        mode,
+        on_slide_change,
+        class,
+    } = props;
+    let per_view = match transition {
+        CarouselTransition::Slide => items_per_view.max(1),
+        CarouselTransition::Fade => 1,
+    };
+    // The carousel moves one slide at a time, until its last slide is visible.
+    let positions = slides.len().saturating_sub(per_view) + 1;
+    let nav = use_carousel_nav(positions, mode, on_slide_change);
+
+    let current = nav.current();
+    let total = slides.len();
+    let (track_class, track_style) = match transition {
+        CarouselTransition::Slide => (
+            "freyr-carousel-track",
+            format!(
+                "transform: translateX(-{}%);",
+                current as f64 * 100.0 / per_view as f64
+            ),
+        ),
+        CarouselTransition::Fade => ("freyr-carousel-track freyr-carousel-fade", String::new()),
+    };
+    let slide_style = format!("flex-basis: {}%;", 100.0 / per_view as f64);
+    let slides = slides
+        .into_iter()
+        .enumerate()
+        .map(|(index, slide)| (index, slide, (current..current + per_view).contains(&index)));
+
+    rsx! {
+        CarouselFrame { nav, class, arrows, empty: total == 0,
+            div { class: "freyr-carousel-viewport",
+                div { class: track_class, style: track_style,
+                    for (index, slide, visible) in slides {
                         div {
-                            class: if (*current_index)() == index { "carousel-number active" } else { "carousel-number" },
-                            onclick: move |_| current_index.set(index),
-                            "{index + 1}"
+                            key: "{index}",
+                            class: if visible { "freyr-carousel-slide active" } else { "freyr-carousel-slide" },
+                            style: "{slide_style}",
+                            role: "group",
+                            aria_roledescription: "slide",
+                            aria_label: "{index + 1} of {total}",
+                            aria_hidden: if !visible { Some("true") } else { None },
+                            "inert": if !visible { Some("true") } else { None },
+                            {slide}
                         }
                     }
                 }
             }
+            if dots && positions > 1 {
+                CarouselDots { nav, count: positions }
+            }
         }
     }
 }
@@ -0,0 +1,319 @@
+use crate::assets::data_table_styles::DATA_TABLE_STYLES;
+use crate::enums::data_table_enums::{DataTableProps, SortDirection};
+use crate::spinner::Spinner;
+use crate::styles::StyleOnce;
+use dioxus::prelude::*;
+
+/// Displays rows of any type in a table, with client-side sorting, text filtering, pagination and row selection.
+/// Columns are described with `Column`, see its documentation for custom cells and sorting.
+///
+/// ```rust,ignore
+/// #[derive(Clone, PartialEq)]
+/// struct User {
+///     name: String,
+///     email: String,
+///     age: u32,
+/// }
+///
+/// #[component]
+/// fn Users(users: Vec<User>, loading: bool) -> Element {
+///     let columns = vec![
+///         Column::new("Name", |user: &User| user.name.clone()),
+///         Column::new("Email", |user: &User| user.email.clone())
+///             .cell(|user: &User| rsx! { a { href: "mailto:{user.email}", "{user.email}" } }),
+///         Column::new("Age", |user: &User| user.age.to_string()).width("80px"),
+///     ];
+///
+///     rsx! {
+///         DataTable {
+///             rows: users,
+///             columns,
+///             loading,
+///             filterable: true,
+///             page_size: 10,
+///             selectable: true,
+///             on_selection_change: move |selected: Vec<User>| tracing::info!("{} selected", selected.len()),
+///             empty_message: "No user yet",
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn DataTable<T: Clone + PartialEq + 'static>(props: DataTableProps<T>) -> Element {
+    let mut filter = use_signal(String::new);
+    let mut sort = use_signal(|| None::<(usize, SortDirection)>);
+    let mut page = use_signal(|| 0usize);
+    let mut selected = use_signal(Vec::<T>::new);
+
+    let DataTableProps {
+        rows,
+        columns,
+        loading,
+        filterable,
+        filter_placeholder,
+        page_size,
+        selectable,
+        on_selection_change,
+        empty_message,
+        class,
+    } = props;
+
+    // Rows removed by the parent are no longer selected
+    let selection = selected
+        .read()
+        .iter()
+        .filter(|row| rows.contains(row))
+        .cloned()
+        .collect::<Vec<T>>();
+    let mut set_selection = move |selection: Vec<T>| {
+        selected.set(selection.clone());
+        if let Some(on_selection_change) = on_selection_change {
+            on_selection_change.call(selection);
+        }
+    };
+
+    let query = filter().trim().to_lowercase();
+    let mut visible = rows
+        .iter()
+        .filter(|row| {
+            query.is_empty()
+                || columns
+                    .iter()
+                    .filter(|column| column.filterable)
+                    .any(|column| (column.accessor)(row).to_lowercase().contains(&query))
+        })
+        .cloned()
+        .collect::<Vec<T>>();
+
+    if let Some((index, direction)) = sort() {
+        if let Some(column) = columns.get(index) {
+            visible.sort_by(|a, b| match direction {
+                SortDirection::Ascending => column.compare(a, b),
+                SortDirection::Descending => column.compare(b, a),
+            });
+        }
+    }
+
+    let total = visible.len();
+    let page_size = page_size.filter(|size| *size > 0);
+    let page_count = page_size.map_or(1, |size| total.div_ceil(size).max(1));
+    let current_page = page().min(page_count - 1);
+    let page_rows = match page_size {
+        Some(size) => visible
+            .into_iter()
+            .skip(current_page * size)
+            .take(size)
+            .collect::<Vec<T>>(),
+        None => visible,
+    };
+
+    let column_count = columns.len() + usize::from(selectable);
+    let all_selected = !page_rows.is_empty() && page_rows.iter().all(|row| selection.contains(row));
+
+    let headers = columns.iter().enumerate().map(|(index, column)| {
+        let sorted = sort().filter(|(sorted, _)| *sorted == index).map(|(_, direction)| direction);
+        let aria_sort = sorted.map_or("none", |direction| direction.as_aria_sort());
+        let width = column
+            .width
+            .as_ref()
+            .map(|width| format!("width: {width};"))
+            .unwrap_or_default();
+        let icon = match sorted {
+            Some(SortDirection::Ascending) => "M12 19V5M5 12l7-7 7 7",
+            Some(SortDirection::Descending) => "M12 5v14M19 12l-7 7-7-7",
+            None => "M7 15l5 5 5-5M7 9l5-5 5 5",
+        };
+
+        rsx! {
+            th {
+                key: "{index}",
+                scope: "col",
+                style: "{width}",
+                "aria-sort": if column.sortable { aria_sort },
+                if column.sortable {
+                    button {
+                        class: "freyr-data-table-sort",
+                        r#type: "button",
+                        onclick: move |_| {
+                            sort.set(match sort() {
+                                Some((sorted, SortDirection::Ascending)) if sorted == index => {
+                                    Some((index, SortDirection::Descending))
+                                }
+                                Some((sorted, SortDirection::Descending)) if sorted == index => None,
+                                _ => Some((index, SortDirection::Ascending)),
+                            });
+                            page.set(0);
+                        },
+                        "{column.header}"
+                        svg {
+                            class: if sorted.is_some() { "freyr-data-table-sort-icon sorted" } else { "freyr-data-table-sort-icon" },
+                            xmlns: "http://www.w3.org/2000/svg",
+                            view_box: "0 0 24 24",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            "aria-hidden": "true",
+                            path { d: "{icon}" }
+                        }
+                    }
+                } else {
+                    "{column.header}"
+                }
+            }
+        }
+    });
+
+    let body_rows = page_rows.iter().enumerate().map(|(index, row)| {
+        let is_selected = selection.contains(row);
+        let toggle_row = {
+            let (row, selection) = (row.clone(), selection.clone());
+            move |_| {
+                let mut next = selection.clone();
+                match next.iter().position(|selected| *selected == row) {
+                    Some(position) => {
+                        next.remove(position);
+                    }
+                    None => next.push(row.clone()),
+                }
+                set_selection(next);
+            }
+        };
+
+        rsx! {
+            tr { key: "{index}", class: if is_selected { "selected" },
+                if selectable {
+                    td { class: "freyr-data-table-select",
+                        input {
+                            r#type: "checkbox",
+                            checked: is_selected,
+                            aria_label: "Select row",
+                            onchange: toggle_row,
+                        }
+                    }
+                }
+                for column in columns.iter() {
+                    td {
+                        if let Some(cell) = &column.cell {
+                            {cell(row)}
+                        } else {
+                            "{(column.accessor)(row)}"
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    let toggle_page = {
+        let (page_rows, selection) = (page_rows.clone(), selection.clone());
+        move |_| {
+            let mut next = selection.clone();
+            if all_selected {
+                next.retain(|row| !page_rows.contains(row));
+            } else {
+                next.extend(
+                    page_rows
+                        .iter()
+                        .filter(|row| !selection.contains(row))
+                        .cloned(),
+                );
+            }
+            set_selection(next);
+        }
+    };
+
+    let empty_message = if query.is_empty() {
+        empty_message.unwrap_or_else(|| "No data to display".to_string())
+    } else {
+        "No matching rows".to_string()
+    };
+    let filter_placeholder = filter_placeholder.unwrap_or_else(|| "Search…".to_string());
+    let class = class.unwrap_or_default();
+
+    rsx! {
+        StyleOnce { css: DATA_TABLE_STYLES }
+
+        div { class: "freyr-data-table-wrapper {class}",
+            if filterable {
+                input {
+                    class: "freyr-data-table-filter",
+                    r#type: "search",
+                    placeholder: "{filter_placeholder}",
+                    aria_label: "{filter_placeholder}",
+                    value: "{filter}",
+                    oninput: move |event| {
+                        filter.set(event.value());
+                        page.set(0);
+                    },
+                }
+            }
+
+            div { class: "freyr-data-table-scroll",
+                table { class: "freyr-data-table", "aria-busy": "{loading}",
+                    thead {
+                        tr {
+                            if selectable {
+                                th { class: "freyr-data-table-select", scope: "col",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: all_selected,
+                                        disabled: loading || page_rows.is_empty(),
+                                        aria_label: "Select all rows",
+                                        onchange: toggle_page,
+                                    }
+                                }
+                            }
+                            {headers}
+                        }
+                    }
+                    tbody {
+                        if loading {
+                            tr {
+                                td { class: "freyr-data-table-state", colspan: "{column_count}",
+                                    div { class: "freyr-data-table-loading",
+                                        Spinner { height: "32px".to_string(), width: "32px".to_string() }
+                                    }
+                                }
+                            }
+                        } else if page_rows.is_empty() {
+                            tr {
+                                td { class: "freyr-data-table-state", colspan: "{column_count}",
+                                    "{empty_message}"
+                                }
+                            }
+                        } else {
+                            {body_rows}
+                        }
+                    }
+                }
+            }
+
+            if let Some(size) = page_size.filter(|_| !loading && total > 0) {
+                div { class: "freyr-data-table-pagination",
+                    span {
+                        "{current_page * size + 1}–{(current_page * size + size).min(total)} of {total}"
+                    }
+                    nav { class: "freyr-data-table-pages", aria_label: "Pagination",
+                        button {
+                            class: "freyr-data-table-page-button",
+                            r#type: "button",
+                            disabled: current_page == 0,
+                            onclick: move |_| page.set(current_page.saturating_sub(1)),
+                            "Previous"
+                        }
+                        span { "Page {current_page + 1} of {page_count}" }
+                        button {
+                            class: "freyr-data-table-page-button",
+                            r#type: "button",
+                            disabled: current_page + 1 >= page_count,
+                            onclick: move |_| page.set(current_page + 1),
+                            "Next"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
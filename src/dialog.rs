@@ -1,15 +1,20 @@
 use crate::assets::dialog_styles::DIALOG_STYLES;
-use crate::DialogWithoutButtonProps;
-#[allow(non_snake_case)]
+use crate::enums::dialog_enums::{ConfirmDialogProps, DialogProps, DialogWithoutButtonProps};
+use crate::scripts::dialog_script::DIALOG_SCRIPT;
+use crate::scripts::{eval, js_string};
+use crate::styles::StyleOnce;
+use crate::unique_id::use_unique_id;
+use dioxus::document::Eval;
 use dioxus::prelude::*;
 
 /// Usage with Tailwind (but Tailwind is an option):
-/// ```rust
-/// use dioxus::prelude::Element;
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use freyr::prelude::*;
 ///
 /// #[component]
 /// fn MyDialog() -> Element {
-///  let mut show_dialog = use_signal(|| false);
+///     let mut show_dialog = use_signal(|| false);
 ///
 ///     let dialog_props = DialogWithoutButtonProps {
 ///         show_modal: show_dialog,
@@ -27,9 +32,11 @@ use dioxus::prelude::*;
 ///             "mt-4 bg-purple-800 text-white px-4 py-2 rounded-lg hover:bg-purple-900 transition".to_string()
 ///         ),
 ///         cross_svg_class: Some("w-6 h-6 absolute top-4 right-4 text-gray-500 hover:text-black cursor-pointer".to_string()),
+///         title: Some("New To-Do".to_string()),
+///         close_on_escape: None,
+///         close_on_backdrop: Some(true),
 ///     };
 ///
-///
 ///     rsx! {
 ///         div { class: "min-h-screen bg-gray-100 flex items-center justify-center",
 ///             button {
@@ -38,37 +45,236 @@ use dioxus::prelude::*;
 ///                 "Show Dialog"
 ///             }
 ///
-///             DialogWithoutButton { ..dialog_props }
+///             Dialog { ..dialog_props }
 ///         }
 ///     }
+/// }
 /// ```
+///
+/// The dialog is announced as a modal by screen readers, traps the focus while open, locks the page scroll
+/// and gives the focus back to the button that opened it once closed.
 #[component]
 pub fn Dialog(mut props: DialogWithoutButtonProps) -> Element {
     rsx! {
-        style { "{DIALOG_STYLES}" }
+        DialogShell {
+            show_modal: props.show_modal,
+            wrap_class: props.wrap_class.clone(),
+            cross_svg_class: props.cross_svg_class.clone(),
+            title: props.title.clone(),
+            close_on_escape: props.close_on_escape.unwrap_or(true),
+            close_on_backdrop: props.close_on_backdrop.unwrap_or_default(),
+            on_dismiss: move |_| props.show_modal.set(false),
+            actions: rsx! {
+                button {
+                    class: if let Some(class) = &props.close_button_class {
+                        class.to_string()
+                    } else {
+                        "freyr-dialog-close-button".to_string()
+                    },
+                    onclick: move |_| props.show_modal.set(false),
+                    if let Some(label) = &props.close_button_label {
+                        {label.clone()}
+                    } else {
+                        "Close"
+                    }
+                }
+            },
+            if let Some(content) = &props.dialog_content {
+                div { class: "freyr-dialog-body", {content} }
+            } else {
+                p { class: "freyr-dialog-placeholder", "You may add some text here." }
+            }
+        }
+    }
+}
+
+/// A button opening a dialog, which owns its own open state.
+/// ```rust,ignore
+/// rsx! {
+///     DialogWithButton {
+///         label: "Show Dialog".to_string(),
+///         dialog_content: rsx! {
+///             h2 { "Add a new To-Do" }
+///             input { r#type: "text", placeholder: "Enter task..." }
+///         },
+///         wrap_class: String::new(), // An empty class keeps the default dialog styles
+///         close_button_label: "Close".to_string(),
+///     }
+/// }
+/// ```
+#[component]
+pub fn DialogWithButton(props: DialogProps) -> Element {
+    let mut show_modal = use_signal(|| false);
+
+    rsx! {
+        button {
+            class: props.dialog_button_class.clone().unwrap_or_else(|| "freyr-dialog-open-button".to_string()),
+            onclick: move |_| show_modal.set(true),
+            "{props.label}"
+        }
+        Dialog {
+            show_modal,
+            dialog_content: props.dialog_content.clone(),
+            wrap_class: props.wrap_class.clone(),
+            close_button_class: props.close_button_class.clone(),
+            close_button_label: props.close_button_label.clone(),
+            cross_svg_class: props.cross_svg_class.clone(),
+            title: props.title.clone(),
+            close_on_escape: props.close_on_escape.unwrap_or(true),
+            close_on_backdrop: props.close_on_backdrop.unwrap_or_default(),
+        }
+    }
+}
+
+/// A button opening a confirmation dialog. `on_choice` receives `true` when the user confirms,
+/// and `false` when they cancel or close the dialog.
+/// ```rust,ignore
+/// let mut todos = use_signal(Vec::<String>::new);
+///
+/// rsx! {
+///     ConfirmDialog {
+///         label: "Delete all".to_string(),
+///         dialog_content: rsx! { p { "Do you really want to delete every To-Do?" } },
+///         confirm_button_label: "Delete".to_string(),
+///         on_choice: move |confirmed: bool| {
+///             if confirmed {
+///                 todos.clear();
+///             }
+///         },
+///     }
+/// }
+/// ```
+#[component]
+pub fn ConfirmDialog(props: ConfirmDialogProps) -> Element {
+    let mut show_modal = use_signal(|| false);
+    let on_choice = props.on_choice;
+    let mut choose = move |confirmed: bool| {
+        show_modal.set(false);
+        on_choice.call(confirmed);
+    };
+
+    rsx! {
+        button {
+            class: props.dialog_button_class.clone().unwrap_or_else(|| "freyr-dialog-open-button".to_string()),
+            onclick: move |_| show_modal.set(true),
+            "{props.label}"
+        }
+        DialogShell {
+            show_modal,
+            wrap_class: props.wrap_class.clone().unwrap_or_default(),
+            cross_svg_class: props.cross_svg_class.clone(),
+            title: props.title.clone(),
+            close_on_escape: props.close_on_escape.unwrap_or(true),
+            close_on_backdrop: props.close_on_backdrop.unwrap_or_default(),
+            alert: true,
+            on_dismiss: move |_| choose(false),
+            actions: rsx! {
+                button {
+                    class: props.cancel_button_class.clone().unwrap_or_else(|| "freyr-dialog-cancel-button".to_string()),
+                    onclick: move |_| choose(false),
+                    {props.cancel_button_label.clone().unwrap_or_else(|| "Cancel".to_string())}
+                }
+                button {
+                    class: props.confirm_button_class.clone().unwrap_or_else(|| "freyr-dialog-close-button".to_string()),
+                    onclick: move |_| choose(true),
+                    {props.confirm_button_label.clone().unwrap_or_else(|| "Confirm".to_string())}
+                }
+            },
+            if let Some(content) = &props.dialog_content {
+                div { class: "freyr-dialog-body", {content} }
+            } else {
+                p { class: "freyr-dialog-placeholder", "Are you sure?" }
+            }
+        }
+    }
+}
+
+/// The overlay, wrapper and close icon shared by every dialog.
+///
+/// While open, the focus is trapped inside the dialog and the page scroll is locked.
+/// On close, the focus goes back to the element that was focused before opening it, usually the trigger button.
+#[component]
+fn DialogShell(
+    show_modal: Signal<bool>,
+    wrap_class: String,
+    cross_svg_class: Option<String>,
+    title: Option<String>,
+    close_on_escape: bool,
+    close_on_backdrop: bool,
+    #[props(default)] alert: bool,
+    on_dismiss: EventHandler<()>,
+    actions: Element,
+    children: Element,
+) -> Element {
+    let dialog_id = use_unique_id("dialog");
+    let title_id = format!("{dialog_id}-title");
+    let mut focus_guard = use_hook(|| CopyValue::new(None::<Eval>));
+
+    let script_dialog_id = dialog_id.clone();
+    use_effect(move || {
+        if show_modal() {
+            let script = DIALOG_SCRIPT.replace("{dialog_id}", &js_string(&script_dialog_id));
+            focus_guard.set(eval(&script));
+        } else if let Some(guard) = focus_guard.write().take() {
+            let _ = guard.send(true);
+        }
+    });
+
+    use_drop(move || {
+        if let Some(guard) = focus_guard
+            .try_write()
+            .ok()
+            .and_then(|mut guard| guard.take())
+        {
+            let _ = guard.send(true);
+        }
+    });
+
+    rsx! {
+        StyleOnce { css: DIALOG_STYLES }
         div {
-            if *props.show_modal.read() {
-                div { class: "dialog-overlay",
+            if show_modal() {
+                div {
+                    class: "freyr-dialog-overlay",
+                    onclick: move |_| {
+                        if close_on_backdrop {
+                            on_dismiss.call(());
+                        }
+                    },
                     div {
-                        class: if props.wrap_class.is_empty() {
-                            "dialog-wrap".to_string()
+                        id: "{dialog_id}",
+                        class: if wrap_class.is_empty() {
+                            "freyr-dialog-wrap".to_string()
                         } else {
-                            props.wrap_class.clone()
+                            wrap_class.clone()
+                        },
+                        role: if alert { "alertdialog" } else { "dialog" },
+                        aria_modal: "true",
+                        aria_labelledby: if title.is_some() { Some(title_id.clone()) } else { None },
+                        tabindex: "-1",
+                        onclick: move |evt| evt.stop_propagation(),
+                        onkeydown: move |evt| {
+                            if close_on_escape && evt.key() == Key::Escape {
+                                evt.stop_propagation();
+                                on_dismiss.call(());
+                            }
                         },
 
                         button {
-                            class: "dialog-close-icon",
-                            onclick: move |_| props.show_modal.set(false),
+                            class: "freyr-dialog-close-icon",
+                            aria_label: "Close dialog",
+                            onclick: move |_| on_dismiss.call(()),
                             svg {
                                 xmlns: "http://www.w3.org/2000/svg",
                                 fill: "none",
                                 view_box: "0 0 24 24",
                                 stroke_width: "2",
                                 stroke: "currentColor",
-                                class: if let Some(class) = &props.cross_svg_class {
+                                "aria-hidden": "true",
+                                class: if let Some(class) = &cross_svg_class {
                                     class.to_string()
                                 } else {
-                                    "dialog-cross-svg".to_string()
+                                    "freyr-dialog-cross-svg".to_string()
                                 },
                                 path {
                                     stroke_linecap: "round",
@@ -78,30 +284,16 @@ pub fn Dialog(mut props: DialogWithoutButtonProps) -> Element {
                             }
                         }
 
-                        if let Some(content) = &props.dialog_content {
-                            div { class: "dialog-body", {content} }
-                        } else {
-                            p { class: "dialog-placeholder", "You may add some text here." }
+                        if let Some(title) = &title {
+                            h2 { id: "{title_id}", class: "freyr-dialog-title", "{title}" }
                         }
 
-                        div { class: "dialog-actions",
-                            button {
-                                class: if let Some(class) = &props.close_button_class {
-                                    class.to_string()
-                                } else {
-                                    "dialog-close-button"
-                                },
-                                onclick: move |_| props.show_modal.set(false),
-                                if let Some(label) = &props.close_button_label {
-                                    {label.clone()}
-                                } else {
-                                    "Close"
-                                }
-                            }
-                        }
+                        {children}
+
+                        div { class: "freyr-dialog-actions", {actions} }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
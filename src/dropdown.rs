@@ -1,15 +1,205 @@
 use crate::assets::dropdown_styles::DROPDOWN_STYLES;
-use crate::enums::dropdown_enums::{
-    DropdownColorScheme, DropdownConfig, DropdownHoverColor, DropdownItem, DropdownLabelsColor,
-    DropdownTitleColor,
-};
+use crate::enums::dropdown_enums::DropdownConfig;
+use crate::enums::floating_enums::Placement;
+use crate::enums::navbar_enums::ActiveMatch;
+use crate::floating::{use_floating, UNMEASURED_STYLE};
+use crate::nav_bar::current_path;
+use crate::styles::StyleOnce;
+use crate::theme::use_theme;
+use crate::time::sleep;
+use crate::unique_id::use_unique_id;
 use crate::DropdownButtonConfig;
+use dioxus::core::Task;
 use dioxus::prelude::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How long the typed characters are kept to find the item starting with them.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Open state and keyboard focus of a dropdown, following the WAI-ARIA menu button pattern:
+/// opening the menu focuses an item, the arrow keys, Home, End and typeahead move between the items,
+/// and Escape closes the menu and gives the focus back to the toggle.
+#[derive(Clone, Copy)]
+struct MenuState {
+    is_open: Signal<bool>,
+    active: CopyValue<Option<usize>>,
+    toggle: CopyValue<Option<Rc<MountedData>>>,
+    items: CopyValue<Vec<Option<Rc<MountedData>>>>,
+    typeahead: CopyValue<String>,
+    typeahead_task: CopyValue<Option<Task>>,
+}
+
+fn use_menu_state() -> MenuState {
+    MenuState {
+        is_open: use_signal(|| false),
+        active: use_hook(|| CopyValue::new(None)),
+        toggle: use_hook(|| CopyValue::new(None)),
+        items: use_hook(|| CopyValue::new(Vec::new())),
+        typeahead: use_hook(|| CopyValue::new(String::new())),
+        typeahead_task: use_hook(|| CopyValue::new(None)),
+    }
+}
+
+impl MenuState {
+    fn is_open(&self) -> bool {
+        (self.is_open)()
+    }
+
+    /// Opens the menu and focuses the item at `index` once it is mounted.
+    fn open(&mut self, index: usize) {
+        self.is_open.set(true);
+        self.focus_item(index);
+    }
+
+    /// Closes the menu, giving the focus back to the toggle when `return_focus` is `true`.
+    fn close(&mut self, return_focus: bool) {
+        self.is_open.set(false);
+        self.active.set(None);
+        if return_focus {
+            if let Some(toggle) = self.toggle.peek().clone() {
+                spawn(async move {
+                    let _ = toggle.set_focus(true).await;
+                });
+            }
+        }
+    }
+
+    fn focus_item(&mut self, index: usize) {
+        self.active.set(Some(index));
+        if let Some(Some(item)) = self.items.peek().get(index).cloned() {
+            spawn(async move {
+                let _ = item.set_focus(true).await;
+            });
+        }
+    }
+
+    fn mount_toggle(&mut self, toggle: Rc<MountedData>) {
+        self.toggle.set(Some(toggle));
+    }
+
+    /// Keeps the mounted item at `index`, focusing it when it was waiting to be focused.
+    fn mount_item(&mut self, index: usize, item: Rc<MountedData>) {
+        {
+            let mut items = self.items.write();
+            if items.len() <= index {
+                items.resize(index + 1, None);
+            }
+            items[index] = Some(item);
+        }
+        if *self.active.peek() == Some(index) {
+            self.focus_item(index);
+        }
+    }
+
+    fn on_toggle_click(&mut self) {
+        if self.is_open() {
+            self.close(false);
+        } else {
+            self.open(0);
+        }
+    }
+
+    /// Opens the menu from the toggle: ArrowDown focuses the first item and ArrowUp the last one.
+    /// Enter and Space click the toggle.
+    fn on_toggle_keydown(&mut self, event: KeyboardEvent, count: usize) {
+        match event.key() {
+            Key::ArrowDown if count > 0 => {
+                event.prevent_default();
+                self.open(0);
+            }
+            Key::ArrowUp if count > 0 => {
+                event.prevent_default();
+                self.open(count - 1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the focus between the items with their `labels`, or closes the menu.
+    fn on_menu_keydown(&mut self, event: KeyboardEvent, labels: &[String]) {
+        let count = labels.len();
+        if count == 0 {
+            return;
+        }
+        let current = *self.active.peek();
+
+        match event.key() {
+            Key::ArrowDown => {
+                event.prevent_default();
+                self.focus_item(current.map_or(0, |index| (index + 1) % count));
+            }
+            Key::ArrowUp => {
+                event.prevent_default();
+                self.focus_item(current.map_or(count - 1, |index| (index + count - 1) % count));
+            }
+            Key::Home => {
+                event.prevent_default();
+                self.focus_item(0);
+            }
+            Key::End => {
+                event.prevent_default();
+                self.focus_item(count - 1);
+            }
+            Key::Escape => {
+                event.prevent_default();
+                event.stop_propagation();
+                self.close(true);
+            }
+            Key::Tab => self.close(false),
+            Key::Character(character)
+                if !event.modifiers().ctrl()
+                    && !event.modifiers().meta()
+                    && !event.modifiers().alt()
+                    && !character.trim().is_empty() =>
+            {
+                event.prevent_default();
+                self.typeahead(&character, current, labels);
+            }
+            _ => {}
+        }
+    }
+
+    /// Focuses the next item starting with the characters typed in the last `TYPEAHEAD_TIMEOUT`.
+    fn typeahead(&mut self, character: &str, current: Option<usize>, labels: &[String]) {
+        let search = {
+            let mut typeahead = self.typeahead.write();
+            typeahead.push_str(&character.to_lowercase());
+            typeahead.clone()
+        };
+
+        if let Some(task) = self.typeahead_task.write().take() {
+            task.cancel();
+        }
+        let mut typeahead = self.typeahead;
+        self.typeahead_task.set(Some(spawn(async move {
+            sleep(TYPEAHEAD_TIMEOUT).await;
+            typeahead.write().clear();
+        })));
+
+        // A new search starts after the focused item, a longer one may still match it
+        let count = labels.len();
+        let start = match current {
+            Some(index) if search.chars().count() == 1 => index + 1,
+            Some(index) => index,
+            None => 0,
+        };
+        let found = (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&index| labels[index].to_lowercase().starts_with(&search));
+        if let Some(index) = found {
+            self.focus_item(index);
+        }
+    }
+}
 
 /// `DropdownMenu` is a customizable dropdown menu component.
 /// You can pass a `DropdownConfig` to customize the list of items,
 /// background color, and text color.
 ///
+/// The menu is keyboard accessible: the arrow keys, Home, End and typing the start of a label move
+/// between the items, and Escape closes the menu and focuses the toggle again.
+///
 /// # Props:
 /// - `config`: A `DropdownConfig` that specifies the items and styles for the dropdown menu.
 ///
@@ -21,8 +211,8 @@ use dioxus::prelude::*;
 /// #[component]
 /// fn Home() -> Element {
 ///     let dropdown_items = vec![
-///         DropdownItem { label: "Home".to_string(), url: Some("/".to_string()) },
-///         DropdownItem { label: "About".to_string(), url: Some("/about".to_string()) },
+///         DropdownItem { label: "Home".to_string(), url: Some("/".into()) },
+///         DropdownItem { label: "About".to_string(), url: Some("/about".into()) },
 ///         // without routing
 ///         DropdownItem::without_url("A Label without route"),
 ///         // Or like that
@@ -35,7 +225,7 @@ use dioxus::prelude::*;
 ///         background_color: DropdownColorScheme::Freyr,
 ///         title_color: DropdownTitleColor::Light,
 ///         labels_color: DropdownLabelsColor::Light,
-///         hover_color: DropdownHoverColor::Custom("#03346E"),
+///         hover_color: DropdownHoverColor::Custom("#03346E".into()),
 ///     };
 ///
 ///     rsx! {
@@ -46,13 +236,49 @@ use dioxus::prelude::*;
 ///
 /// **NOTE:** The name **_"config_dropdown"_** is mandatory.
 ///
+/// The item linking to the current route, according to `active_match`, gets the `active` class and
+/// `aria-current="page"`.
 
 #[component]
-pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
-    let mut is_open = use_signal(|| false);
+pub fn DropdownMenu(
+    config_dropdown: DropdownConfig,
+    #[props(default)] active_match: ActiveMatch,
+) -> Element {
+    let theme = use_theme();
+    let current = current_path();
+    let active_items = config_dropdown
+        .label
+        .iter()
+        .map(|item| {
+            item.url.as_ref().is_some_and(|url| {
+                current
+                    .as_ref()
+                    .is_some_and(|path| active_match.is_active(path, url))
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut menu = use_menu_state();
+    let dropdown_id = use_unique_id("dropdown");
+    let toggle_id = format!("{dropdown_id}-toggle");
+    let content_id = format!("{dropdown_id}-content");
+    let position_style = use_floating(
+        toggle_id.clone(),
+        content_id.clone(),
+        menu.is_open(),
+        Placement::BottomStart,
+        4.0,
+    )
+    .map(|position| position.style())
+    .unwrap_or_else(|| UNMEASURED_STYLE.to_string());
+    let labels: Vec<String> = config_dropdown
+        .label
+        .iter()
+        .map(|item| item.label.clone())
+        .collect();
+    let item_count = labels.len();
 
     let style_tag = rsx! {
-        style { "{DROPDOWN_STYLES}" }
+        StyleOnce { css: DROPDOWN_STYLES }
     };
 
     let arrow_down_svg = rsx! {
@@ -64,7 +290,7 @@ pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
             stroke_width: "2",
             stroke_linecap: "round",
             stroke_linejoin: "round",
-            class: "feather feather-chevron-down",
+            "aria-hidden": "true",
             path { d: "M6 9l6 6 6-6" }
         }
     };
@@ -78,52 +304,71 @@ pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
             stroke_width: "2",
             stroke_linecap: "round",
             stroke_linejoin: "round",
-            class: "feather feather-chevron-up",
+            "aria-hidden": "true",
             path { d: "M18 15l-6-6-6 6" }
         }
     };
 
     rsx! {
         {style_tag},
-            if is_open() {
+            if menu.is_open() {
                 div {
-                    class: "dropdown-overlay",
-                    onclick: move |_| is_open.set(false),
+                    class: "freyr-dropdown-overlay",
+                    onclick: move |_| menu.close(false),
                 }
             }
         div {
-            div { class: "dropdown",
+            div { class: "freyr-dropdown",
                 button {
-                    class: "dropdown-toggle",
-                    style: "background-color: {config_dropdown.background_color.as_css_class()}; color: {config_dropdown.title_color.as_css_class()};",
-                    onclick: move |_| is_open.set(!is_open()),
+                    id: "{toggle_id}",
+                    class: "freyr-dropdown-toggle",
+                    style: "background-color: {config_dropdown.background_color.to_css_color(&theme)}; color: {config_dropdown.title_color.to_css_color(&theme)};",
+                    aria_haspopup: "menu",
+                    aria_expanded: "{menu.is_open()}",
+                    aria_controls: if menu.is_open() { Some(content_id.clone()) } else { None },
+                    onmounted: move |event| menu.mount_toggle(event.data()),
+                    onclick: move |_| menu.on_toggle_click(),
+                    onkeydown: move |event| menu.on_toggle_keydown(event, item_count),
                     "{config_dropdown.title}"
 
-                    match is_open() {
+                    match menu.is_open() {
                         true => arrow_up_svg,
                         false => arrow_down_svg,
                     }
                 }
 
                 div {
-                    match is_open() {
+                    match menu.is_open() {
                         true => {
                             rsx! {
                                 div {
-                                    class: "dropdown-content",
-                                    style: "background-color: {config_dropdown.background_color.as_css_class()}; color: {config_dropdown.labels_color.as_css_class()};",
-                                    for item in config_dropdown.label {
+                                    id: "{content_id}",
+                                    class: "freyr-dropdown-content",
+                                    role: "menu",
+                                    aria_labelledby: "{toggle_id}",
+                                    onkeydown: move |event| menu.on_menu_keydown(event, &labels),
+                                    style: "background-color: {config_dropdown.background_color.to_css_color(&theme)}; color: {config_dropdown.labels_color.to_css_color(&theme)}; {position_style}",
+                                    for (index , item) in config_dropdown.label.into_iter().enumerate() {
                                         if let Some(url) = &item.url {
                                             Link {
-                                                class: "link",
+                                                class: if active_items[index] { "freyr-link active" } else { "freyr-link" },
                                                 to: url.clone(),
-                                                style: "color: {config_dropdown.labels_color.as_css_class()}; --custom_color: {config_dropdown.hover_color.as_css_class()};",
+                                                role: "menuitem",
+                                                aria_current: if active_items[index] { "page" } else { "false" },
+                                                tabindex: "-1",
+                                                onmounted: move |event: MountedEvent| menu.mount_item(index, event.data()),
+                                                onclick: move |_| menu.close(true),
+                                                style: "color: {config_dropdown.labels_color.to_css_color(&theme)}; --freyr-dropdown-hover-color: {config_dropdown.hover_color.to_css_color(&theme)};",
                                                 "{item.label}"
                                             }
                                         } else {
                                             span {
-                                                class: "link",
-                                                style: "color: {config_dropdown.labels_color.as_css_class()};",
+                                                class: "freyr-link",
+                                                role: "menuitem",
+                                                tabindex: "-1",
+                                                aria_disabled: "true",
+                                                onmounted: move |event| menu.mount_item(index, event.data()),
+                                                style: "color: {config_dropdown.labels_color.to_css_color(&theme)};",
                                                 "{item.label}"
                                             }
                                         }
@@ -141,8 +386,11 @@ pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
     }
 }
 
+/// Dropdown whose items are buttons calling the `onclick` handlers, with the same keyboard support as the **`DropdownMenu`**.
+/// The menu closes once an item is clicked.
+///
 /// Usage example of a dropdown that passes onclick events to its items like a dummy counter:
-/// ```rust
+/// ```rust,ignore
 ///let mut counter = use_signal(|| 0);
 ///
 ///     let dropdown_items = vec!["Increment".to_string(), "Decrement".to_string()];
@@ -160,7 +408,7 @@ pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
 ///         background_color: DropdownColorScheme::Dark,
 ///         title_color: DropdownTitleColor::Light,
 ///         labels_color: DropdownLabelsColor::Light,
-///         hover_color: DropdownHoverColor::Custom("#03346E"),
+///         hover_color: DropdownHoverColor::Custom("#03346E".into()),
 ///     };
 ///
 ///     rsx! {
@@ -173,10 +421,29 @@ pub fn DropdownMenu(config_dropdown: DropdownConfig) -> Element {
 /// ```
 #[component]
 pub fn DropdownMenuButton(config_dropdown: DropdownButtonConfig) -> Element {
-    let mut is_open = use_signal(|| false);
+    let theme = use_theme();
+    let mut menu = use_menu_state();
+    let dropdown_id = use_unique_id("dropdown");
+    let toggle_id = format!("{dropdown_id}-toggle");
+    let content_id = format!("{dropdown_id}-content");
+    let position_style = use_floating(
+        toggle_id.clone(),
+        content_id.clone(),
+        menu.is_open(),
+        Placement::BottomStart,
+        4.0,
+    )
+    .map(|position| position.style())
+    .unwrap_or_else(|| UNMEASURED_STYLE.to_string());
+    // Only the labels with a handler are rendered
+    let item_count = config_dropdown
+        .labels
+        .len()
+        .min(config_dropdown.onclick.len());
+    let labels: Vec<String> = config_dropdown.labels[..item_count].to_vec();
 
     let style_tag = rsx! {
-        style { "{DROPDOWN_STYLES}" }
+        StyleOnce { css: DROPDOWN_STYLES }
     };
 
     let arrow_down_svg = rsx! {
@@ -188,7 +455,7 @@ pub fn DropdownMenuButton(config_dropdown: DropdownButtonConfig) -> Element {
             stroke_width: "2",
             stroke_linecap: "round",
             stroke_linejoin: "round",
-            class: "feather feather-chevron-down",
+            "aria-hidden": "true",
             path { d: "M6 9l6 6 6-6" }
         }
     };
@@ -202,46 +469,64 @@ pub fn DropdownMenuButton(config_dropdown: DropdownButtonConfig) -> Element {
             stroke_width: "2",
             stroke_linecap: "round",
             stroke_linejoin: "round",
-            class: "feather feather-chevron-up",
+            "aria-hidden": "true",
             path { d: "M18 15l-6-6-6 6" }
         }
     };
 
     rsx! {
         {style_tag},
-            if is_open() {
+            if menu.is_open() {
                 div {
-                    class: "dropdown-overlay",
-                    onclick: move |_| is_open.set(false),
+                    class: "freyr-dropdown-overlay",
+                    onclick: move |_| menu.close(false),
                 }
             }
         div {
-            div { class: "dropdown",
-                onclick: move |_| is_open.set(is_open()),
+            div { class: "freyr-dropdown",
                 button {
-                    class: "dropdown-toggle",
-                    style: "background-color: {config_dropdown.background_color.as_css_class()}; color: {config_dropdown.title_color.as_css_class()};",
-                    onclick: move |_| is_open.set(!is_open()),
+                    id: "{toggle_id}",
+                    class: "freyr-dropdown-toggle",
+                    style: "background-color: {config_dropdown.background_color.to_css_color(&theme)}; color: {config_dropdown.title_color.to_css_color(&theme)};",
+                    aria_haspopup: "menu",
+                    aria_expanded: "{menu.is_open()}",
+                    aria_controls: if menu.is_open() { Some(content_id.clone()) } else { None },
+                    onmounted: move |event| menu.mount_toggle(event.data()),
+                    onclick: move |_| menu.on_toggle_click(),
+                    onkeydown: move |event| menu.on_toggle_keydown(event, item_count),
                     "{config_dropdown.title}"
 
-                    match is_open() {
+                    match menu.is_open() {
                         true => arrow_up_svg,
                         false => arrow_down_svg,
                     }
                 }
 
                 div {
-                    match is_open() {
+                    match menu.is_open() {
                         true => {
                             rsx! {
                                 div {
-                                    class: "dropdown-content",
-                                    style: "background-color: {config_dropdown.background_color.as_css_class()}; color: {config_dropdown.labels_color.as_css_class()};",
-                                    for (label , onclick_handler) in config_dropdown.labels.iter().zip(config_dropdown.onclick.iter()) {
+                                    id: "{content_id}",
+                                    class: "freyr-dropdown-content",
+                                    role: "menu",
+                                    aria_labelledby: "{toggle_id}",
+                                    onkeydown: move |event| menu.on_menu_keydown(event, &labels),
+                                    style: "background-color: {config_dropdown.background_color.to_css_color(&theme)}; color: {config_dropdown.labels_color.to_css_color(&theme)}; {position_style}",
+                                    for (index , (label , onclick_handler)) in config_dropdown.labels.iter().zip(config_dropdown.onclick.iter()).enumerate() {
                                         button {
-                                            onclick: onclick_handler.clone(),
-                                            class: "button-config",
-                                            style: "color: {config_dropdown.labels_color.as_css_class()}; --custom_color: {config_dropdown.hover_color.as_css_class()};",
+                                            role: "menuitem",
+                                            tabindex: "-1",
+                                            onmounted: move |event| menu.mount_item(index, event.data()),
+                                            onclick: {
+                                                let onclick_handler = *onclick_handler;
+                                                move |event| {
+                                                    onclick_handler.call(event);
+                                                    menu.close(true);
+                                                }
+                                            },
+                                            class: "freyr-button-config",
+                                            style: "color: {config_dropdown.labels_color.to_css_color(&theme)}; --freyr-dropdown-hover-color: {config_dropdown.hover_color.to_css_color(&theme)};",
                                             "{label}"
                                         }
                                     }
@@ -1,3 +1,5 @@
+use crate::enums::color_enums::Color;
+use crate::enums::theme_enums::Theme;
 use dioxus::prelude::*;
 
 #[derive(PartialEq, Clone)]
@@ -9,8 +11,8 @@ pub enum AccordionColor {
 impl AccordionColor {
     pub fn to_css_class(&self) -> &'static str {
         match self {
-            AccordionColor::Freyr => "accordion-freyr",
-            AccordionColor::Black => "accordion-black",
+            AccordionColor::Freyr => "freyr-accordion-freyr",
+            AccordionColor::Black => "freyr-accordion-black",
         }
     }
 }
@@ -21,17 +23,29 @@ pub enum AccordionIconColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl AccordionIconColor {
-    /// Returns the CSS class or custom color for the navigation items.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
         match self {
-            AccordionIconColor::Freyr => "#3795BD",
-            AccordionIconColor::Dark => "#000",
-            AccordionIconColor::Light => "#fff",
-            AccordionIconColor::Custom(color) => color,
+            AccordionIconColor::Freyr => "#3795BD".to_string(),
+            AccordionIconColor::Dark => "#000".to_string(),
+            AccordionIconColor::Light => "#fff".to_string(),
+            AccordionIconColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The color of the accordion icon: `Freyr` is the primary color of the `Theme`, `Dark` and `Light`
+    /// its dark and light colors.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            AccordionIconColor::Freyr => theme.palette.primary.clone(),
+            AccordionIconColor::Dark => theme.palette.dark.clone(),
+            AccordionIconColor::Light => theme.palette.light.clone(),
+            AccordionIconColor::Custom(color) => color.clone(),
         }
     }
 }
@@ -64,13 +78,15 @@ pub struct AccordionCustomProps {
 #[derive(Props, PartialEq, Clone)]
 pub struct AccordionLeftProps {
     pub accordion_width: Option<String>,
-    pub accordion_header_bg_color: Option<String>,
+    #[props(into)]
+    pub accordion_header_bg_color: Option<Color>,
     pub title: String,
     pub children: Element,
     pub accordion_open_max_height: Option<String>,
     pub box_shadow: Option<String>,
     pub border_radius: Option<String>,
-    pub title_color: Option<String>,
+    #[props(into)]
+    pub title_color: Option<Color>,
     pub title_font_size: Option<String>,
     pub title_font_weight: Option<String>,
     pub content_padding: Option<String>,
@@ -15,24 +15,46 @@ pub enum ButtonColor {
 impl ButtonColor {
     pub fn to_css_class(&self) -> &'static str {
         match self {
-            ButtonColor::Freyr => "btn-freyr",
-            ButtonColor::Primary => "btn-primary",
-            ButtonColor::Success => "btn-success",
-            ButtonColor::Danger => "btn-danger",
-            ButtonColor::Black => "btn-black",
-            ButtonColor::Transparent => "btn-transparent",
+            ButtonColor::Freyr => "freyr-btn-freyr",
+            ButtonColor::Primary => "freyr-btn-primary",
+            ButtonColor::Success => "freyr-btn-success",
+            ButtonColor::Danger => "freyr-btn-danger",
+            ButtonColor::Black => "freyr-btn-black",
+            ButtonColor::Transparent => "freyr-btn-transparent",
         }
     }
 }
 
+/// Target of a **`BasicButton`**: a variant of your `Routable` enum or an external URL.
 #[derive(PartialEq, Clone)]
 pub struct ButtonUrl {
-    pub url: String,
+    pub url: NavigationTarget,
 }
 
+impl ButtonUrl {
+    /// Creates a `ButtonUrl` from a route, checked at compile time, or an external URL.
+    ///
+    /// ```rust,ignore
+    /// ButtonUrl::new(Route::Home {});
+    /// ButtonUrl::new("https://dioxuslabs.com");
+    /// ```
+    pub fn new(url: impl Into<NavigationTarget>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+/// Props of the **`EventButton`** component.
+///
+/// While `loading` is `true`, the button shows a spinner and ignores clicks.
+/// `button_type` defaults to `"button"`; use `"submit"` to submit the surrounding **`Form`**.
 #[derive(Props, PartialEq, Clone)]
 pub struct ButtonProps {
     pub color: ButtonColor,
     pub label: String,
-    pub onclick: EventHandler<MouseEvent>,
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    #[props(default)]
+    pub loading: bool,
+    #[props(default)]
+    pub disabled: bool,
+    pub button_type: Option<String>,
 }
\ No newline at end of file
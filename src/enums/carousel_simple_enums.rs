@@ -4,13 +4,68 @@ use dioxus::prelude::*;
 pub struct CarouselItem {
     pub image_url: Asset,
     pub image_alt: String,
+    /// Text shown at the bottom of the image.
+    pub caption: Option<String>,
+    /// Content laid over the image, e.g. a heading and a call to action.
+    pub overlay: Option<Element>,
 }
 
 impl CarouselItem {
     pub fn new(image_url: impl Into<Asset>, image_alt: String) -> Self {
         Self {
             image_url: image_url.into(),
-            image_alt: image_alt.into(),
+            image_alt,
+            caption: None,
+            overlay: None,
         }
     }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn overlay(mut self, overlay: Element) -> Self {
+        self.overlay = Some(overlay);
+        self
+    }
+}
+
+/// What the carousels do past their last slide and before their first one. With `Loop`, the next slide
+/// of the last one is the first one, and the other way around. With `Bounded`, the carousel stops at
+/// its first and last slides, and the autoplay of `CarouselWithTimer` stops at the last one.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum CarouselMode {
+    #[default]
+    Loop,
+    Bounded,
+}
+
+/// How the **`Carousel`** moves from a slide to another: `Slide` scrolls the slides horizontally,
+/// `Fade` cross-fades them in place.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum CarouselTransition {
+    #[default]
+    Slide,
+    Fade,
+}
+
+#[derive(PartialEq, Clone, Props)]
+pub struct CarouselProps {
+    /// The content of each slide: images, cards, text or videos.
+    pub slides: Vec<Element>,
+    #[props(default)]
+    pub transition: CarouselTransition,
+    /// How many slides are shown side by side, e.g. for product strips. Only used by the `Slide` transition.
+    #[props(default = 1)]
+    pub items_per_view: usize,
+    #[props(default = true)]
+    pub arrows: bool,
+    #[props(default = true)]
+    pub dots: bool,
+    #[props(default)]
+    pub mode: CarouselMode,
+    /// Called with the index of the newly shown slide, the first visible one with several slides per view.
+    pub on_slide_change: Option<EventHandler<usize>>,
+    pub class: Option<String>,
 }
@@ -0,0 +1,611 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A CSS color accepted by every freyr config struct.
+///
+/// Colors can be built at runtime (e.g. from user settings) and are converted from strings,
+/// so `"#3795BD".into()`, `"rgb(55, 149, 189)".into()` or `"var(--brand)".into()` all work:
+/// ```rust,ignore
+/// let brand = Color::parse(&settings.brand_color)?;
+///
+/// let navbar_config = NavbarConfig {
+///     background_color: ColorScheme::Custom(brand.clone()),
+///     nav_item_color: NavItemsColor::Custom(brand.readable_text_color().unwrap_or("white".into())),
+///     ..
+/// };
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub enum Color {
+    /// `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`.
+    Hex(String),
+    Rgb(u8, u8, u8),
+    /// Red, green, blue and an alpha between `0.0` and `1.0`.
+    Rgba(u8, u8, u8, f32),
+    /// Hue in degrees, saturation and lightness in percent.
+    Hsl(f32, f32, f32),
+    /// Hue in degrees, saturation and lightness in percent and an alpha between `0.0` and `1.0`.
+    Hsla(f32, f32, f32, f32),
+    /// A CSS custom property, rendered as `var(--name)`.
+    Var(String),
+    /// A CSS color keyword such as `red`, `transparent` or `currentColor`.
+    Named(String),
+}
+
+/// Error returned by `Color::parse` when a string is not a valid CSS color.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ColorParseError {
+    pub input: String,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color `{}`: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl Color {
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color::Rgb(red, green, blue)
+    }
+
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        Color::Rgba(red, green, blue, alpha.clamp(0.0, 1.0))
+    }
+
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Color::Hsl(
+            hue,
+            saturation.clamp(0.0, 100.0),
+            lightness.clamp(0.0, 100.0),
+        )
+    }
+
+    /// A CSS custom property, with or without its leading `--`.
+    pub fn var(name: impl Into<String>) -> Self {
+        let name = name.into();
+        match name.strip_prefix("--") {
+            Some(name) => Color::Var(name.to_string()),
+            None => Color::Var(name),
+        }
+    }
+
+    /// Parses and validates a CSS color: hex, `rgb()`/`rgba()`, `hsl()`/`hsla()`, `var(--name)`, `--name` or a keyword.
+    pub fn parse(input: &str) -> Result<Self, ColorParseError> {
+        let value = input.trim();
+        let error = |reason| ColorParseError {
+            input: input.to_string(),
+            reason,
+        };
+
+        if let Some(digits) = value.strip_prefix('#') {
+            return match digits.len() {
+                3 | 4 | 6 | 8 if digits.chars().all(|c| c.is_ascii_hexdigit()) => {
+                    Ok(Color::Hex(value.to_string()))
+                }
+                3 | 4 | 6 | 8 => Err(error("hex colors may only contain hexadecimal digits")),
+                _ => Err(error("hex colors must have 3, 4, 6 or 8 digits")),
+            };
+        }
+
+        if value.starts_with("--") {
+            return Ok(Color::var(value));
+        }
+
+        let Some((function, arguments)) = value
+            .strip_suffix(')')
+            .and_then(|value| value.split_once('('))
+        else {
+            return if is_keyword(value) {
+                Ok(Color::Named(value.to_string()))
+            } else if !value.is_empty() && value.chars().all(|c| c.is_ascii_alphabetic()) {
+                Err(error("unknown color keyword"))
+            } else {
+                Err(error("expected a hex, rgb(), hsl(), var() or named color"))
+            };
+        };
+
+        let arguments = arguments
+            .split([',', ' ', '/'])
+            .filter(|argument| !argument.is_empty())
+            .collect::<Vec<_>>();
+
+        match (
+            function.trim().to_ascii_lowercase().as_str(),
+            arguments.as_slice(),
+        ) {
+            ("var", [name]) if name.starts_with("--") => Ok(Color::var(*name)),
+            ("var", _) => Err(error("var() expects a single `--name` argument")),
+            ("rgb" | "rgba", [red, green, blue]) => Ok(Color::Rgb(
+                parse_channel(red).ok_or_else(|| error("invalid red channel"))?,
+                parse_channel(green).ok_or_else(|| error("invalid green channel"))?,
+                parse_channel(blue).ok_or_else(|| error("invalid blue channel"))?,
+            )),
+            ("rgb" | "rgba", [red, green, blue, alpha]) => Ok(Color::Rgba(
+                parse_channel(red).ok_or_else(|| error("invalid red channel"))?,
+                parse_channel(green).ok_or_else(|| error("invalid green channel"))?,
+                parse_channel(blue).ok_or_else(|| error("invalid blue channel"))?,
+                parse_alpha(alpha).ok_or_else(|| error("invalid alpha"))?,
+            )),
+            ("hsl" | "hsla", [hue, saturation, lightness]) => Ok(Color::Hsl(
+                parse_hue(hue).ok_or_else(|| error("invalid hue"))?,
+                parse_percent(saturation).ok_or_else(|| error("invalid saturation"))?,
+                parse_percent(lightness).ok_or_else(|| error("invalid lightness"))?,
+            )),
+            ("hsl" | "hsla", [hue, saturation, lightness, alpha]) => Ok(Color::Hsla(
+                parse_hue(hue).ok_or_else(|| error("invalid hue"))?,
+                parse_percent(saturation).ok_or_else(|| error("invalid saturation"))?,
+                parse_percent(lightness).ok_or_else(|| error("invalid lightness"))?,
+                parse_alpha(alpha).ok_or_else(|| error("invalid alpha"))?,
+            )),
+            ("rgb" | "rgba" | "hsl" | "hsla", _) => Err(error("expected 3 or 4 arguments")),
+            _ => Err(error("unknown color function")),
+        }
+    }
+
+    /// Returns the red, green, blue and alpha channels, or `None` for CSS variables, `currentColor` and
+    /// strings kept as-is by `From<&str>`.
+    pub fn to_rgba(&self) -> Option<(u8, u8, u8, f32)> {
+        match self {
+            Color::Hex(hex) => {
+                let digits = hex.trim_start_matches('#');
+                let expanded = match digits.len() {
+                    3 | 4 => digits.chars().flat_map(|c| [c, c]).collect::<String>(),
+                    _ => digits.to_string(),
+                };
+                let channel = |index: usize| {
+                    expanded
+                        .get(index * 2..index * 2 + 2)
+                        .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                };
+                let alpha = match expanded.len() {
+                    8 => channel(3)? as f32 / 255.0,
+                    _ => 1.0,
+                };
+                Some((channel(0)?, channel(1)?, channel(2)?, alpha))
+            }
+            Color::Rgb(red, green, blue) => Some((*red, *green, *blue, 1.0)),
+            Color::Rgba(red, green, blue, alpha) => Some((*red, *green, *blue, *alpha)),
+            Color::Hsl(hue, saturation, lightness) => {
+                let (red, green, blue) = hsl_to_rgb(*hue, *saturation, *lightness);
+                Some((red, green, blue, 1.0))
+            }
+            Color::Hsla(hue, saturation, lightness, alpha) => {
+                let (red, green, blue) = hsl_to_rgb(*hue, *saturation, *lightness);
+                Some((red, green, blue, *alpha))
+            }
+            Color::Var(_) => None,
+            Color::Named(name) => match name.to_ascii_lowercase().as_str() {
+                "transparent" => Some((0, 0, 0, 0.0)),
+                name => {
+                    let (red, green, blue) = named_rgb(name)?;
+                    Some((red, green, blue, 1.0))
+                }
+            },
+        }
+    }
+
+    /// The WCAG relative luminance, from `0.0` (black) to `1.0` (white).
+    pub fn relative_luminance(&self) -> Option<f64> {
+        let (red, green, blue, _) = self.to_rgba()?;
+        let linear = |channel: u8| {
+            let channel = channel as f64 / 255.0;
+            if channel <= 0.03928 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Some(0.2126 * linear(red) + 0.7152 * linear(green) + 0.0722 * linear(blue))
+    }
+
+    /// The WCAG contrast ratio between two colors, from `1.0` to `21.0`.
+    /// Aim for at least `4.5` for body text.
+    pub fn contrast_ratio(&self, other: &Color) -> Option<f64> {
+        let (first, second) = (self.relative_luminance()?, other.relative_luminance()?);
+        let (lighter, darker) = if first > second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        Some((lighter + 0.05) / (darker + 0.05))
+    }
+
+    /// Whether white text reads better than black text on top of this color, or `None` when its
+    /// channels are unknown, e.g. for a CSS variable.
+    pub fn is_dark(&self) -> Option<bool> {
+        self.relative_luminance().map(|luminance| luminance < 0.179)
+    }
+
+    /// Black or white, whichever is the most readable on top of this color, or `None` when its
+    /// channels are unknown.
+    pub fn readable_text_color(&self) -> Option<Color> {
+        let name = if self.is_dark()? { "white" } else { "black" };
+        Some(Color::Named(name.to_string()))
+    }
+}
+
+/// The CSS named colors, sorted by name so they can be binary searched.
+const NAMED_COLORS: [(&str, (u8, u8, u8)); 148] = [
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
+
+/// Whether `name` is a CSS color keyword, ignoring case.
+fn is_keyword(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    matches!(name.as_str(), "transparent" | "currentcolor") || named_rgb(&name).is_some()
+}
+
+/// The channels of a lowercase CSS named color.
+fn named_rgb(name: &str) -> Option<(u8, u8, u8)> {
+    NAMED_COLORS
+        .binary_search_by_key(&name, |(named, _)| named)
+        .ok()
+        .map(|index| NAMED_COLORS[index].1)
+}
+
+fn parse_channel(value: &str) -> Option<u8> {
+    match value.strip_suffix('%') {
+        Some(percent) => {
+            let percent = percent.parse::<f32>().ok()?;
+            (0.0..=100.0)
+                .contains(&percent)
+                .then(|| (percent * 2.55).round() as u8)
+        }
+        None => value.parse::<u8>().ok(),
+    }
+}
+
+fn parse_alpha(value: &str) -> Option<f32> {
+    let alpha = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None => value.parse::<f32>().ok()?,
+    };
+    (0.0..=1.0).contains(&alpha).then_some(alpha)
+}
+
+fn parse_hue(value: &str) -> Option<f32> {
+    value.trim_end_matches("deg").parse::<f32>().ok()
+}
+
+fn parse_percent(value: &str) -> Option<f32> {
+    let percent = value.strip_suffix('%')?.parse::<f32>().ok()?;
+    (0.0..=100.0).contains(&percent).then_some(percent)
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (u8, u8, u8) {
+    let (saturation, lightness) = (saturation / 100.0, lightness / 100.0);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (red, green, blue) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let offset = lightness - chroma / 2.0;
+    let channel = |value: f32| ((value + offset) * 255.0).round() as u8;
+    (channel(red), channel(green), channel(blue))
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Hex(hex) => write!(f, "{hex}"),
+            Color::Rgb(red, green, blue) => write!(f, "rgb({red}, {green}, {blue})"),
+            Color::Rgba(red, green, blue, alpha) => {
+                write!(f, "rgba({red}, {green}, {blue}, {alpha})")
+            }
+            Color::Hsl(hue, saturation, lightness) => {
+                write!(f, "hsl({hue}, {saturation}%, {lightness}%)")
+            }
+            Color::Hsla(hue, saturation, lightness, alpha) => {
+                write!(f, "hsla({hue}, {saturation}%, {lightness}%, {alpha})")
+            }
+            Color::Var(name) => write!(f, "var(--{name})"),
+            Color::Named(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Color::parse(value)
+    }
+}
+
+/// Parses the string, keeping it as-is when it is not a valid color so the browser decides.
+/// Use `Color::parse` to validate colors coming from users.
+impl From<&str> for Color {
+    fn from(value: &str) -> Self {
+        Color::parse(value).unwrap_or_else(|_| Color::Named(value.to_string()))
+    }
+}
+
+impl From<String> for Color {
+    fn from(value: String) -> Self {
+        Color::from(value.as_str())
+    }
+}
+
+impl From<&String> for Color {
+    fn from(value: &String) -> Self {
+        Color::from(value.as_str())
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color::Rgb(red, green, blue)
+    }
+}
+
+impl From<(u8, u8, u8, f32)> for Color {
+    fn from((red, green, blue, alpha): (u8, u8, u8, f32)) -> Self {
+        Color::rgba(red, green, blue, alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_eq!(
+            Color::parse("#fff").unwrap().to_rgba(),
+            Some((255, 255, 255, 1.0))
+        );
+        assert_eq!(
+            Color::parse("#0f08").unwrap().to_rgba(),
+            Some((0, 255, 0, 136.0 / 255.0))
+        );
+        assert_eq!(
+            Color::parse("#3795BD").unwrap().to_rgba(),
+            Some((55, 149, 189, 1.0))
+        );
+        assert_eq!(
+            Color::parse("#3795BD80").unwrap().to_rgba(),
+            Some((55, 149, 189, 128.0 / 255.0))
+        );
+    }
+
+    #[test]
+    fn rejects_bad_hex_colors() {
+        assert!(Color::parse("#12345").is_err());
+        assert!(Color::parse("#ggg").is_err());
+        assert!(Color::parse("#").is_err());
+    }
+
+    #[test]
+    fn parses_rgb_colors() {
+        assert_eq!(
+            Color::parse("rgb(55, 149, 189)"),
+            Ok(Color::Rgb(55, 149, 189))
+        );
+        assert_eq!(
+            Color::parse("rgba(55, 149, 189, 0.5)"),
+            Ok(Color::Rgba(55, 149, 189, 0.5))
+        );
+        assert_eq!(
+            Color::parse("rgb(100% 0% 0% / 50%)"),
+            Ok(Color::Rgba(255, 0, 0, 0.5))
+        );
+        assert!(Color::parse("rgb(256, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn parses_hsl_colors() {
+        assert_eq!(
+            Color::parse("hsl(120, 100%, 25%)"),
+            Ok(Color::Hsl(120.0, 100.0, 25.0))
+        );
+        assert_eq!(
+            Color::parse("hsla(120deg, 100%, 25%, 0.5)"),
+            Ok(Color::Hsla(120.0, 100.0, 25.0, 0.5))
+        );
+        assert_eq!(
+            Color::Hsl(120.0, 100.0, 25.0).to_rgba(),
+            Some((0, 128, 0, 1.0))
+        );
+        assert!(Color::parse("hsl(120, 100, 25)").is_err());
+    }
+
+    #[test]
+    fn parses_css_variables() {
+        assert_eq!(
+            Color::parse("var(--brand)"),
+            Ok(Color::Var("brand".to_string()))
+        );
+        assert_eq!(Color::parse("--brand"), Ok(Color::Var("brand".to_string())));
+        assert_eq!(Color::var("--brand").to_string(), "var(--brand)");
+        assert!(Color::parse("var(brand)").is_err());
+    }
+
+    #[test]
+    fn validates_keywords() {
+        assert_eq!(
+            Color::parse("RebeccaPurple").unwrap().to_rgba(),
+            Some((102, 51, 153, 1.0))
+        );
+        assert_eq!(
+            Color::parse("transparent").unwrap().to_rgba(),
+            Some((0, 0, 0, 0.0))
+        );
+        assert_eq!(Color::parse("currentColor").unwrap().to_rgba(), None);
+        assert!(Color::parse("notacolor").is_err());
+    }
+
+    #[test]
+    fn computes_contrast() {
+        let (black, white) = (Color::rgb(0, 0, 0), Color::parse("white").unwrap());
+        assert!((black.contrast_ratio(&white).unwrap() - 21.0).abs() < 1e-9);
+        assert_eq!(black.is_dark(), Some(true));
+        assert_eq!(
+            white.readable_text_color(),
+            Some(Color::Named("black".to_string()))
+        );
+    }
+
+    #[test]
+    fn has_no_contrast_without_channels() {
+        let brand = Color::var("brand");
+        assert_eq!(brand.is_dark(), None);
+        assert_eq!(brand.readable_text_color(), None);
+        assert_eq!(brand.contrast_ratio(&Color::rgb(0, 0, 0)), None);
+    }
+}
@@ -0,0 +1,154 @@
+use dioxus::prelude::*;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// Direction of the sorted column of a **`DataTable`**.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the value of the `aria-sort` attribute of the sorted column header.
+    pub fn as_aria_sort(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// Returns the text of a cell of a **`DataTable`** column.
+pub type CellAccessor<T> = Rc<dyn Fn(&T) -> String>;
+/// Renders a cell of a **`DataTable`** column.
+pub type CellRenderer<T> = Rc<dyn Fn(&T) -> Element>;
+/// Compares two rows of a **`DataTable`** to sort them.
+pub type RowComparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// A column of a **`DataTable`**: its `header`, the `accessor` returning the text of a cell,
+/// and an optional `cell` renderer used instead of that text.
+///
+/// The accessor is also used to filter the rows and, unless `sort_by` is set, to sort them:
+/// numbers are compared as numbers, anything else as case-insensitive text.
+///
+/// ```rust,ignore
+/// let columns = vec![
+///     Column::new("Name", |user: &User| user.name.clone()),
+///     Column::new("Age", |user: &User| user.age.to_string()).width("80px"),
+///     Column::new("Status", |user: &User| user.status.to_string())
+///         .cell(|user: &User| rsx! { span { class: "status {user.status}", "{user.status}" } })
+///         .sortable(false),
+/// ];
+/// ```
+#[derive(Clone)]
+pub struct Column<T> {
+    pub header: String,
+    pub accessor: CellAccessor<T>,
+    pub cell: Option<CellRenderer<T>>,
+    pub sort_by: Option<RowComparator<T>>,
+    pub sortable: bool,
+    pub filterable: bool,
+    pub width: Option<String>,
+}
+
+impl<T> Column<T> {
+    /// Creates a sortable and filterable column.
+    pub fn new(header: impl Into<String>, accessor: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            header: header.into(),
+            accessor: Rc::new(accessor),
+            cell: None,
+            sort_by: None,
+            sortable: true,
+            filterable: true,
+            width: None,
+        }
+    }
+
+    /// Renders the cells with `cell` instead of the text returned by the accessor.
+    pub fn cell(mut self, cell: impl Fn(&T) -> Element + 'static) -> Self {
+        self.cell = Some(Rc::new(cell));
+        self
+    }
+
+    /// Sorts the rows with `compare` instead of comparing the accessor values.
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sort_by = Some(Rc::new(compare));
+        self
+    }
+
+    /// Whether clicking the header sorts the rows. Defaults to `true`.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Whether the text filter searches this column. Defaults to `true`.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Sets the CSS width of the column, e.g. `"120px"` or `"20%"`.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Compares two rows the way this column sorts them.
+    pub fn compare(&self, a: &T, b: &T) -> Ordering {
+        if let Some(sort_by) = &self.sort_by {
+            return sort_by(a, b);
+        }
+
+        let (a, b) = ((self.accessor)(a), (self.accessor)(b));
+        match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.total_cmp(&b),
+            _ => a.to_lowercase().cmp(&b.to_lowercase()),
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        self.header == other.header
+            && Rc::ptr_eq(&self.accessor, &other.accessor)
+            && same(&self.cell, &other.cell)
+            && same(&self.sort_by, &other.sort_by)
+            && self.sortable == other.sortable
+            && self.filterable == other.filterable
+            && self.width == other.width
+    }
+}
+
+/// Props of the **`DataTable`** component.
+///
+/// - `filterable` shows a search input filtering the rows on the filterable columns.
+/// - `page_size` splits the rows in pages; every row is shown when it is `None`.
+/// - `selectable` adds a checkbox to every row, `on_selection_change` receives the selected rows.
+/// - `loading` replaces the rows with a `Spinner`, `empty_message` is shown when there is no row to display.
+#[derive(Props, PartialEq, Clone)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static> {
+    pub rows: Vec<T>,
+    pub columns: Vec<Column<T>>,
+    #[props(default)]
+    pub loading: bool,
+    #[props(default)]
+    pub filterable: bool,
+    pub filter_placeholder: Option<String>,
+    pub page_size: Option<usize>,
+    #[props(default)]
+    pub selectable: bool,
+    pub on_selection_change: Option<EventHandler<Vec<T>>>,
+    pub empty_message: Option<String>,
+    pub class: Option<String>,
+}
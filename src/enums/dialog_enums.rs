@@ -1,7 +1,6 @@
 use dioxus::prelude::*;
 
-use dioxus::prelude::*;
-
+/// Props of the **`DialogWithButton`** component: `label` is the text of the button opening the dialog.
 #[derive(Props, PartialEq, Clone)]
 pub struct DialogProps {
     pub label: String,
@@ -11,8 +10,17 @@ pub struct DialogProps {
     pub close_button_class: Option<String>,
     pub close_button_label: Option<String>,
     pub cross_svg_class: Option<String>,
+    /// Rendered as the heading of the dialog and used as its accessible name.
+    pub title: Option<String>,
+    /// Whether Escape closes the dialog. Defaults to `true`.
+    pub close_on_escape: Option<bool>,
+    /// Whether a click outside of the dialog closes it. Defaults to `false`.
+    pub close_on_backdrop: Option<bool>,
 }
 
+/// Props of the **`Dialog`** component, opened and closed through the `show_modal` signal.
+/// The dialog closes on Escape unless `close_on_escape` is `Some(false)`, and on a click outside of it when
+/// `close_on_backdrop` is `Some(true)`.
 #[derive(Props, PartialEq, Clone)]
 pub struct DialogWithoutButtonProps {
     pub show_modal: Signal<bool>,
@@ -21,4 +29,32 @@ pub struct DialogWithoutButtonProps {
     pub close_button_class: Option<String>,
     pub close_button_label: Option<String>,
     pub cross_svg_class: Option<String>,
-}
\ No newline at end of file
+    /// Rendered as the heading of the dialog and used as its accessible name.
+    pub title: Option<String>,
+    /// Whether Escape closes the dialog. Defaults to `true`.
+    pub close_on_escape: Option<bool>,
+    /// Whether a click outside of the dialog closes it. Defaults to `false`.
+    pub close_on_backdrop: Option<bool>,
+}
+
+/// Props of the **`ConfirmDialog`** component. `on_choice` receives `true` when the user confirms,
+/// and `false` when they cancel or close the dialog.
+#[derive(Props, PartialEq, Clone)]
+pub struct ConfirmDialogProps {
+    pub label: String,
+    pub dialog_content: Option<Element>,
+    pub on_choice: EventHandler<bool>,
+    pub dialog_button_class: Option<String>,
+    pub wrap_class: Option<String>,
+    pub confirm_button_class: Option<String>,
+    pub confirm_button_label: Option<String>,
+    pub cancel_button_class: Option<String>,
+    pub cancel_button_label: Option<String>,
+    pub cross_svg_class: Option<String>,
+    /// Rendered as the heading of the dialog and used as its accessible name.
+    pub title: Option<String>,
+    /// Whether Escape closes the dialog. Defaults to `true`.
+    pub close_on_escape: Option<bool>,
+    /// Whether a click outside of the dialog closes it. Defaults to `false`.
+    pub close_on_backdrop: Option<bool>,
+}
@@ -1,5 +1,7 @@
+use crate::enums::color_enums::Color;
+use crate::enums::theme_enums::Theme;
 use dioxus::events::MouseEvent;
-use dioxus::prelude::EventHandler;
+use dioxus::prelude::{EventHandler, NavigationTarget};
 
 /// Represents different background color schemes for the dropdown.
 #[derive(PartialEq, Clone)]
@@ -7,17 +9,29 @@ pub enum DropdownColorScheme {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl DropdownColorScheme {
-    /// Returns the CSS class or custom color for the background.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
         match self {
-            DropdownColorScheme::Freyr => "#3795BD",
-            DropdownColorScheme::Dark => "#1E201E",
-            DropdownColorScheme::Light => "#F7F7F8",
-            DropdownColorScheme::Custom(color) => color,
+            DropdownColorScheme::Freyr => "#3795BD".to_string(),
+            DropdownColorScheme::Dark => "#1E201E".to_string(),
+            DropdownColorScheme::Light => "#F7F7F8".to_string(),
+            DropdownColorScheme::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The background of the dropdown menu: `Freyr` is the primary color of the `Theme`, `Dark` and `Light`
+    /// its dark and light colors.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            DropdownColorScheme::Freyr => theme.palette.primary.clone(),
+            DropdownColorScheme::Dark => theme.palette.dark.clone(),
+            DropdownColorScheme::Light => theme.palette.light.clone(),
+            DropdownColorScheme::Custom(color) => color.clone(),
         }
     }
 }
@@ -28,17 +42,29 @@ pub enum DropdownTitleColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl DropdownTitleColor {
-    /// Returns the CSS class or custom color for the dropdown title.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
         match self {
-            DropdownTitleColor::Freyr => "#3795BD",
-            DropdownTitleColor::Dark => "#1E201E",
-            DropdownTitleColor::Light => "#F7F7F8",
-            DropdownTitleColor::Custom(color) => color,
+            DropdownTitleColor::Freyr => "#3795BD".to_string(),
+            DropdownTitleColor::Dark => "#1E201E".to_string(),
+            DropdownTitleColor::Light => "#F7F7F8".to_string(),
+            DropdownTitleColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The color of the dropdown title, taking the primary, dark or light color of the `Theme` for `Freyr`,
+    /// `Dark` and `Light`.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            DropdownTitleColor::Freyr => theme.palette.primary.clone(),
+            DropdownTitleColor::Dark => theme.palette.dark.clone(),
+            DropdownTitleColor::Light => theme.palette.light.clone(),
+            DropdownTitleColor::Custom(color) => color.clone(),
         }
     }
 }
@@ -49,17 +75,29 @@ pub enum DropdownLabelsColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl DropdownLabelsColor {
-    /// Returns the CSS class or custom color for the dropdown labels.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
         match self {
-            DropdownLabelsColor::Freyr => "#3795BD",
-            DropdownLabelsColor::Dark => "#1E201E",
-            DropdownLabelsColor::Light => "#F7F7F8",
-            DropdownLabelsColor::Custom(color) => color,
+            DropdownLabelsColor::Freyr => "#3795BD".to_string(),
+            DropdownLabelsColor::Dark => "#1E201E".to_string(),
+            DropdownLabelsColor::Light => "#F7F7F8".to_string(),
+            DropdownLabelsColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The color of the dropdown labels: `Freyr`, `Dark` and `Light` follow the primary, dark and light
+    /// colors of the `Theme`.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            DropdownLabelsColor::Freyr => theme.palette.primary.clone(),
+            DropdownLabelsColor::Dark => theme.palette.dark.clone(),
+            DropdownLabelsColor::Light => theme.palette.light.clone(),
+            DropdownLabelsColor::Custom(color) => color.clone(),
         }
     }
 }
@@ -69,39 +107,64 @@ pub enum DropdownHoverColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl DropdownHoverColor {
-    /// Returns the CSS class or custom color for the dropdown background hover color.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
+        match self {
+            DropdownHoverColor::Freyr => "#3795BD".to_string(),
+            DropdownHoverColor::Dark => "#1E201E".to_string(),
+            DropdownHoverColor::Light => "#F7F7F8".to_string(),
+            DropdownHoverColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The background of a hovered dropdown item: the primary color of the `Theme` for `Freyr`, its dark
+    /// color for `Dark` and its light color for `Light`.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
         match self {
-            DropdownHoverColor::Freyr => "#3795BD",
-            DropdownHoverColor::Dark => "#1E201E",
-            DropdownHoverColor::Light => "#F7F7F8",
-            DropdownHoverColor::Custom(color) => color,
+            DropdownHoverColor::Freyr => theme.palette.primary.clone(),
+            DropdownHoverColor::Dark => theme.palette.dark.clone(),
+            DropdownHoverColor::Light => theme.palette.light.clone(),
+            DropdownHoverColor::Custom(color) => color.clone(),
         }
     }
 }
 
 /// `DropdownItem` represents an individual item in the dropdown menu,
-/// containing a label and a URL for navigation.
+/// containing a label and a target for navigation: a variant of your `Routable` enum or an external URL.
 #[derive(PartialEq, Clone)]
 pub struct DropdownItem {
     pub label: String,
-    pub url: Option<String>,
+    pub url: Option<NavigationTarget>,
 }
 
 impl DropdownItem {
-    /// Creates a new `DropdownItem` with a label and an optional URL.
-    /// If the URL is not provided, it defaults to `None`.
-    pub fn new(label: impl Into<String>, url: Option<String>) -> Self {
+    /// Creates a new `DropdownItem` with a label and an optional target.
+    /// If the target is not provided, it defaults to `None`.
+    pub fn new(label: impl Into<String>, url: Option<NavigationTarget>) -> Self {
         Self {
             label: label.into(),
             url,
         }
     }
 
+    /// Creates a `DropdownItem` linking to a route or an external URL.
+    ///
+    /// ```rust,ignore
+    /// DropdownItem::with_url("Home", Route::Home {});
+    /// DropdownItem::with_url("Dioxus", "https://dioxuslabs.com");
+    /// ```
+    pub fn with_url(label: impl Into<String>, url: impl Into<NavigationTarget>) -> Self {
+        Self {
+            label: label.into(),
+            url: Some(url.into()),
+        }
+    }
+
     /// Creates a `DropdownItem` with only a label and no URL.
     pub fn without_url(label: impl Into<String>) -> Self {
         Self {
@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// Where a tooltip, popover or dropdown is placed around its anchor: on a side, then aligned with
+/// the start, the center or the end of that side. It flips to the opposite side when there is not
+/// enough room in the viewport.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum Placement {
+    #[default]
+    Top,
+    TopStart,
+    TopEnd,
+    Bottom,
+    BottomStart,
+    BottomEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+    Right,
+    RightStart,
+    RightEnd,
+}
+
+/// Side of the anchor a floating element is placed on.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Alignment of a floating element along the side of its anchor.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Align {
+    Start,
+    Center,
+    End,
+}
+
+impl Placement {
+    pub(crate) fn side(&self) -> Side {
+        match self {
+            Placement::Top | Placement::TopStart | Placement::TopEnd => Side::Top,
+            Placement::Bottom | Placement::BottomStart | Placement::BottomEnd => Side::Bottom,
+            Placement::Left | Placement::LeftStart | Placement::LeftEnd => Side::Left,
+            Placement::Right | Placement::RightStart | Placement::RightEnd => Side::Right,
+        }
+    }
+
+    pub(crate) fn align(&self) -> Align {
+        match self {
+            Placement::TopStart
+            | Placement::BottomStart
+            | Placement::LeftStart
+            | Placement::RightStart => Align::Start,
+            Placement::TopEnd | Placement::BottomEnd | Placement::LeftEnd | Placement::RightEnd => {
+                Align::End
+            }
+            Placement::Top | Placement::Bottom | Placement::Left | Placement::Right => {
+                Align::Center
+            }
+        }
+    }
+
+    /// Returns the same placement on the opposite side.
+    pub(crate) fn flipped(&self) -> Placement {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::TopStart => Placement::BottomStart,
+            Placement::TopEnd => Placement::BottomEnd,
+            Placement::Bottom => Placement::Top,
+            Placement::BottomStart => Placement::TopStart,
+            Placement::BottomEnd => Placement::TopEnd,
+            Placement::Left => Placement::Right,
+            Placement::LeftStart => Placement::RightStart,
+            Placement::LeftEnd => Placement::RightEnd,
+            Placement::Right => Placement::Left,
+            Placement::RightStart => Placement::LeftStart,
+            Placement::RightEnd => Placement::LeftEnd,
+        }
+    }
+
+    /// Returns the side as written in the `data-placement` attribute, used to style the arrow.
+    pub fn as_side_str(&self) -> &'static str {
+        match self.side() {
+            Side::Top => "top",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+            Side::Right => "right",
+        }
+    }
+}
+
+/// Props of the **`Tooltip`** component, describing the element wrapped in it.
+///
+/// The tooltip shows `content` on hover and focus after `open_delay` (defaults to 300 ms),
+/// and hides it after `close_delay` (defaults to 100 ms) or on Escape.
+#[derive(Props, PartialEq, Clone)]
+pub struct TooltipProps {
+    pub content: String,
+    #[props(default)]
+    pub placement: Placement,
+    pub open_delay: Option<Duration>,
+    pub close_delay: Option<Duration>,
+    #[props(default = true)]
+    pub arrow: bool,
+    pub class: Option<String>,
+    pub children: Element,
+}
+
+/// Props of the **`Popover`** component: `children` is the content of the button toggling the popover,
+/// and `content` is displayed next to it until a click outside, Escape or a second click on the button.
+#[derive(Props, PartialEq, Clone)]
+pub struct PopoverProps {
+    pub content: Element,
+    /// Accessible name of the popover.
+    pub title: Option<String>,
+    #[props(default = Placement::Bottom)]
+    pub placement: Placement,
+    #[props(default = true)]
+    pub arrow: bool,
+    pub trigger_class: Option<String>,
+    pub class: Option<String>,
+    pub children: Element,
+}
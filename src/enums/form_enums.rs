@@ -0,0 +1,42 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Checks the value of a form field, returning the error message to display when it is invalid.
+pub type Validator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+
+/// Boxed future returned by an `AsyncValidator`.
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// Checks the value of a form field asynchronously, e.g. against a server.
+pub type AsyncValidator<T> = Rc<dyn Fn(T) -> ValidationFuture>;
+
+/// Submit handler of the **`Form`** component, built from any closure returning a future:
+/// `on_submit: move || async move { save(email.value()).await }`.
+///
+/// The form stays in its submitting state until the returned future completes.
+#[derive(Clone)]
+pub struct SubmitHandler(Rc<dyn Fn() -> Pin<Box<dyn Future<Output = ()>>>>);
+
+impl SubmitHandler {
+    /// Calls the handler, returning the future to await.
+    pub fn call(&self) -> Pin<Box<dyn Future<Output = ()>>> {
+        (self.0)()
+    }
+}
+
+impl<F, Fut> From<F> for SubmitHandler
+where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    fn from(handler: F) -> Self {
+        Self(Rc::new(move || Box::pin(handler())))
+    }
+}
+
+impl PartialEq for SubmitHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -0,0 +1,156 @@
+use dioxus::prelude::*;
+
+/// Size of the form controls: padding and font size of the inputs, checkboxes, radios, switches and selects.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum InputSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl InputSize {
+    /// Returns the CSS class of the size.
+    pub fn as_css_class(&self) -> &'static str {
+        match self {
+            InputSize::Small => "small",
+            InputSize::Medium => "medium",
+            InputSize::Large => "large",
+        }
+    }
+}
+
+/// A choice of the **`Radio`** and **`Select`** components: the `label` displayed and the `value` it sets.
+#[derive(PartialEq, Clone, Debug)]
+pub struct SelectOption<T> {
+    pub label: String,
+    pub value: T,
+    pub disabled: bool,
+}
+
+impl<T> SelectOption<T> {
+    pub fn new(label: impl Into<String>, value: T) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            disabled: false,
+        }
+    }
+
+    /// Renders the option without letting it be chosen.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// Props of the **`TextInput`** component. `input_type` defaults to `"text"`, e.g. `"email"` or `"password"`.
+/// The `error` replaces the `helper_text` and marks the input as invalid.
+#[derive(Props, PartialEq, Clone)]
+pub struct TextInputProps {
+    pub value: Signal<String>,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    pub helper_text: Option<String>,
+    pub error: Option<String>,
+    pub input_type: Option<String>,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub readonly: bool,
+    #[props(default)]
+    pub required: bool,
+    #[props(default)]
+    pub size: InputSize,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    /// Called when the control loses focus, e.g. to touch a form field.
+    pub onblur: Option<EventHandler<FocusEvent>>,
+}
+
+/// Props of the **`TextArea`** component. `rows` defaults to 4.
+#[derive(Props, PartialEq, Clone)]
+pub struct TextAreaProps {
+    pub value: Signal<String>,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    pub helper_text: Option<String>,
+    pub error: Option<String>,
+    pub rows: Option<u32>,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub readonly: bool,
+    #[props(default)]
+    pub required: bool,
+    #[props(default)]
+    pub size: InputSize,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    /// Called when the control loses focus, e.g. to touch a form field.
+    pub onblur: Option<EventHandler<FocusEvent>>,
+}
+
+/// Props of the **`Checkbox`** and **`Switch`** components.
+#[derive(Props, PartialEq, Clone)]
+pub struct CheckboxProps {
+    pub checked: Signal<bool>,
+    pub label: String,
+    pub helper_text: Option<String>,
+    pub error: Option<String>,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub readonly: bool,
+    #[props(default)]
+    pub size: InputSize,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    /// Called when the control loses focus, e.g. to touch a form field.
+    pub onblur: Option<EventHandler<FocusEvent>>,
+}
+
+/// Props of the **`Radio`** component: a group of radio buttons setting `value` to the chosen option.
+/// The options are displayed in a row when `inline` is `true`.
+#[derive(Props, PartialEq, Clone)]
+pub struct RadioProps<T: Clone + PartialEq + 'static> {
+    pub value: Signal<T>,
+    pub options: Vec<SelectOption<T>>,
+    pub label: Option<String>,
+    pub helper_text: Option<String>,
+    pub error: Option<String>,
+    #[props(default)]
+    pub inline: bool,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub readonly: bool,
+    #[props(default)]
+    pub size: InputSize,
+    pub name: Option<String>,
+    pub class: Option<String>,
+    /// Called when one of the radio buttons loses focus, e.g. to touch a form field.
+    pub onblur: Option<EventHandler<FocusEvent>>,
+}
+
+/// Props of the **`Select`** component, setting `value` to the chosen option.
+#[derive(Props, PartialEq, Clone)]
+pub struct SelectProps<T: Clone + PartialEq + 'static> {
+    pub value: Signal<T>,
+    pub options: Vec<SelectOption<T>>,
+    pub label: Option<String>,
+    pub helper_text: Option<String>,
+    pub error: Option<String>,
+    #[props(default)]
+    pub disabled: bool,
+    #[props(default)]
+    pub readonly: bool,
+    #[props(default)]
+    pub required: bool,
+    #[props(default)]
+    pub size: InputSize,
+    pub id: Option<String>,
+    pub class: Option<String>,
+    /// Called when the control loses focus, e.g. to touch a form field.
+    pub onblur: Option<EventHandler<FocusEvent>>,
+}
@@ -6,3 +6,10 @@ pub(crate) mod tabs_enums;
 pub(crate) mod accordion_enums;
 pub(crate) mod dialog_enums;
 pub(crate) mod spinner_enums;
+pub(crate) mod theme_enums;
+pub(crate) mod color_enums;
+pub(crate) mod data_table_enums;
+pub(crate) mod input_enums;
+pub(crate) mod form_enums;
+pub(crate) mod toast_enums;
+pub(crate) mod floating_enums;
@@ -1,5 +1,7 @@
+use crate::enums::color_enums::Color;
+use crate::enums::theme_enums::Theme;
 use dioxus::prelude::*;
-/// Configuration options for the **`Navbar`** component, including color schemes and menu settings.
+use std::fmt;
 
 /// Represents different background color schemes for the navbar.
 #[derive(PartialEq, Clone)]
@@ -7,7 +9,7 @@ pub enum ColorScheme {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 #[derive(PartialEq, Clone)]
@@ -22,29 +24,53 @@ pub enum HeaderColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl ColorScheme {
-    /// Returns the CSS class or custom color for the background.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
         match self {
-            ColorScheme::Freyr => "#3795BD",
-            ColorScheme::Dark => "#222",
-            ColorScheme::Light => "#fff",
-            ColorScheme::Custom(color) => color,
+            ColorScheme::Freyr => "#3795BD".to_string(),
+            ColorScheme::Dark => "#222".to_string(),
+            ColorScheme::Light => "#fff".to_string(),
+            ColorScheme::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The background of the navbar: `Freyr` is the primary color of the `Theme`, `Dark` and `Light` its
+    /// dark and light colors.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            ColorScheme::Freyr => theme.palette.primary.clone(),
+            ColorScheme::Dark => theme.palette.dark.clone(),
+            ColorScheme::Light => theme.palette.light.clone(),
+            ColorScheme::Custom(color) => color.clone(),
         }
     }
 }
 
 impl HeaderColor {
-    /// Returns the CSS class or custom color for the background.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
+        match self {
+            HeaderColor::Freyr => "#3795BD".to_string(),
+            HeaderColor::Dark => "#222".to_string(),
+            HeaderColor::Light => "#fff".to_string(),
+            HeaderColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The color of the navbar header: `Freyr`, `Dark` and `Light` follow the primary, dark and light
+    /// colors of the `Theme`.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
         match self {
-            HeaderColor::Freyr => "#3795BD",
-            HeaderColor::Dark => "#222",
-            HeaderColor::Light => "#fff",
-            HeaderColor::Custom(color) => color,
+            HeaderColor::Freyr => theme.palette.primary.clone(),
+            HeaderColor::Dark => theme.palette.dark.clone(),
+            HeaderColor::Light => theme.palette.light.clone(),
+            HeaderColor::Custom(color) => color.clone(),
         }
     }
 }
@@ -55,17 +81,29 @@ pub enum NavItemsColor {
     Freyr,
     Dark,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl NavItemsColor {
-    /// Returns the CSS class or custom color for the navigation items.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
+        match self {
+            NavItemsColor::Freyr => "#3795BD".to_string(),
+            NavItemsColor::Dark => "#000".to_string(),
+            NavItemsColor::Light => "#fff".to_string(),
+            NavItemsColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The color of the navigation items, taking the primary, dark or light color of the `Theme` for
+    /// `Freyr`, `Dark` and `Light`.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
         match self {
-            NavItemsColor::Freyr => "#3795BD",
-            NavItemsColor::Dark => "#000",
-            NavItemsColor::Light => "#fff",
-            NavItemsColor::Custom(color) => color,
+            NavItemsColor::Freyr => theme.palette.primary.clone(),
+            NavItemsColor::Dark => theme.palette.dark.clone(),
+            NavItemsColor::Light => theme.palette.light.clone(),
+            NavItemsColor::Custom(color) => color.clone(),
         }
     }
 }
@@ -76,36 +114,268 @@ pub enum IconColor {
     Freyr,
     White,
     Black,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl IconColor {
-    /// Returns the CSS class or custom color for the icons.
-    pub fn as_css_class(&self) -> &'static str {
+    /// Returns the default hex color of the variant, ignoring the active `Theme`.
+    #[deprecated(note = "use to_css_color(&theme)")]
+    pub fn as_css_class(&self) -> String {
+        match self {
+            IconColor::Freyr => "#3795BD".to_string(),
+            IconColor::White => "#fff".to_string(),
+            IconColor::Black => "#000".to_string(),
+            IconColor::Custom(color) => color.to_string(),
+        }
+    }
+
+    /// The stroke of the hamburger and cross icons: `Freyr` is the primary color of the `Theme`, `White`
+    /// its light color and `Black` its dark color.
+    pub fn to_css_color(&self, theme: &Theme) -> Color {
+        match self {
+            IconColor::Freyr => theme.palette.primary.clone(),
+            IconColor::White => theme.palette.light.clone(),
+            IconColor::Black => theme.palette.dark.clone(),
+            IconColor::Custom(color) => color.clone(),
+        }
+    }
+}
+
+/// How the current route is compared to the link of a navigation item to mark it as active.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ActiveMatch {
+    /// The item is active when the current path is its link.
+    #[default]
+    Exact,
+    /// The item is also active on the nested routes of its link, e.g. `/blog` on `/blog/my-post`.
+    /// A `/` link only matches itself.
+    Prefix,
+}
+
+impl ActiveMatch {
+    /// Returns whether a navigation item linking to `target` is active on the `current` path.
+    /// Query strings, fragments and trailing slashes are ignored.
+    pub fn is_active(&self, current: &str, target: &NavigationTarget) -> bool {
+        fn normalize(path: &str) -> &str {
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            match path.trim_end_matches('/') {
+                "" => "/",
+                path => path,
+            }
+        }
+
+        let link = match target {
+            NavigationTarget::Internal(path) | NavigationTarget::External(path) => path,
+        };
+        let (current, link) = (normalize(current), normalize(link));
         match self {
-            IconColor::Freyr => "#3795BD",
-            IconColor::White => "#fff",
-            IconColor::Black => "#000",
-            IconColor::Custom(color) => color,
+            ActiveMatch::Exact => current == link,
+            ActiveMatch::Prefix => {
+                current == link
+                    || (link != "/"
+                        && current
+                            .strip_prefix(link)
+                            .is_some_and(|rest| rest.starts_with('/')))
+            }
         }
     }
 }
 
+/// A navigation item of the navbars: a link, or a nested menu when it has `children`.
+///
+/// Build it with `NavItem::new` or `NavItem::menu` and the chained setters:
+/// ```rust,ignore
+/// let nav_items = vec![
+///     NavItem::new("Home", Route::Home {}),
+///     NavItem::new("Blog", Route::Blog {}).badge("New"),
+///     NavItem::menu(
+///         "Components",
+///         vec![
+///             NavItem::new("Buttons", Route::Buttons {}),
+///             NavItem::new("Charts", Route::Charts {}).disabled(),
+///         ],
+///     ),
+///     NavItem::new("GitHub", "https://github.com/cbdefontenay/freyr")
+///         .icon(rsx! { img { src: GITHUB_ICON, alt: "" } })
+///         .new_tab(),
+/// ];
+/// ```
+#[derive(PartialEq, Clone)]
+pub struct NavItem {
+    pub label: String,
+    pub target: Option<NavigationTarget>,
+    pub icon: Option<Element>,
+    pub badge: Option<String>,
+    pub disabled: bool,
+    pub new_tab: bool,
+    pub children: Vec<NavItem>,
+}
+
+impl NavItem {
+    /// Creates an item linking to a route of your `Routable` enum or to an external URL.
+    pub fn new(label: impl Into<String>, target: impl Into<NavigationTarget>) -> Self {
+        Self {
+            label: label.into(),
+            target: Some(target.into()),
+            icon: None,
+            badge: None,
+            disabled: false,
+            new_tab: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an item opening a nested menu with the given `children`.
+    pub fn menu(label: impl Into<String>, children: Vec<NavItem>) -> Self {
+        Self {
+            label: label.into(),
+            target: None,
+            icon: None,
+            badge: None,
+            disabled: false,
+            new_tab: false,
+            children,
+        }
+    }
+
+    /// Builds the items from the former parallel `nav_items` and `nav_links` vectors.
+    /// Unlike zipping them, it fails when their lengths differ instead of dropping the extra entries.
+    pub fn from_pairs(
+        labels: Vec<String>,
+        targets: Vec<NavigationTarget>,
+    ) -> Result<Vec<NavItem>, NavItemError> {
+        if labels.len() != targets.len() {
+            return Err(NavItemError::LengthMismatch {
+                labels: labels.len(),
+                targets: targets.len(),
+            });
+        }
+
+        Ok(labels
+            .into_iter()
+            .zip(targets)
+            .map(|(label, target)| NavItem::new(label, target))
+            .collect())
+    }
+
+    /// Renders an icon before the label.
+    pub fn icon(mut self, icon: Element) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Renders a small badge after the label, e.g. a counter or "New".
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    /// Renders the item without letting it be clicked.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Opens the target in a new tab, usually for external URLs.
+    pub fn new_tab(mut self) -> Self {
+        self.new_tab = true;
+        self
+    }
+
+    /// Adds an item to the nested menu.
+    pub fn child(mut self, child: NavItem) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns whether the item, or one of its children, matches the `current` path.
+    pub fn is_active(&self, current: &str, active_match: ActiveMatch) -> bool {
+        self.target
+            .as_ref()
+            .is_some_and(|target| active_match.is_active(current, target))
+            || self
+                .children
+                .iter()
+                .any(|child| child.is_active(current, active_match))
+    }
+
+    /// Checks the item and its children, returning the first problem found.
+    pub fn validate(&self) -> Result<(), NavItemError> {
+        if self.label.trim().is_empty() {
+            return Err(NavItemError::EmptyLabel);
+        }
+        if self.target.is_none() && self.children.is_empty() && !self.disabled {
+            return Err(NavItemError::MissingTarget {
+                label: self.label.clone(),
+            });
+        }
+        if self.target.is_some() && !self.children.is_empty() {
+            return Err(NavItemError::TargetWithChildren {
+                label: self.label.clone(),
+            });
+        }
+
+        self.children.iter().try_for_each(NavItem::validate)
+    }
+}
+
+/// A problem found while building or validating `NavItem`s.
+#[derive(PartialEq, Clone, Debug)]
+pub enum NavItemError {
+    /// `NavItem::from_pairs` received a different number of labels and targets.
+    LengthMismatch { labels: usize, targets: usize },
+    /// An item has an empty label.
+    EmptyLabel,
+    /// An enabled item has neither a target nor children, so clicking it does nothing.
+    MissingTarget { label: String },
+    /// A nested menu also has a target, which is never reachable.
+    TargetWithChildren { label: String },
+}
+
+impl fmt::Display for NavItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavItemError::LengthMismatch { labels, targets } => {
+                write!(f, "got {labels} navigation labels for {targets} targets")
+            }
+            NavItemError::EmptyLabel => write!(f, "a navigation item has an empty label"),
+            NavItemError::MissingTarget { label } => {
+                write!(
+                    f,
+                    "navigation item `{label}` has neither a target nor children"
+                )
+            }
+            NavItemError::TargetWithChildren { label } => write!(
+                f,
+                "navigation item `{label}` has children, so its target is never used"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NavItemError {}
+
 /// Represents a dropdown configuration with its label and items.
+///
+/// `label` is the text of the toggle shown among the navigation items, `title` an optional heading inside the
+/// opened dropdown and `items` its `(label, target)` links. The dropdown is rendered before the navigation item at
+/// index `position`, or after every item when `position` is `None`.
+/// The colors default to the ones of the navbar.
 #[derive(PartialEq, Clone)]
 pub struct DropdownConfigNavBar {
     pub label: String,
     pub title: Option<String>,
-    pub items: Vec<(String, String)>,
+    pub items: Vec<(String, NavigationTarget)>,
     pub background_color: Option<ColorScheme>,
     pub title_color: Option<NavItemsColor>,
     pub item_color: Option<NavItemsColor>,
+    pub position: Option<usize>,
 }
 
 impl DropdownConfigNavBar {
     pub fn new(
         label: String,
-        items: Vec<(String, String)>,
+        items: Vec<(String, NavigationTarget)>,
         title: Option<String>,
         background_color: Option<ColorScheme>,
         title_color: Option<NavItemsColor>,
@@ -118,47 +388,176 @@ impl DropdownConfigNavBar {
             background_color,
             title_color,
             item_color,
+            position: None,
         }
     }
+
+    /// Renders the dropdown before the navigation item at `index`.
+    pub fn at_position(mut self, index: usize) -> Self {
+        self.position = Some(index);
+        self
+    }
 }
 
 /// Configuration struct for the **`Navbar`** component.
+///
+/// `nav_items` are described with `NavItem`, whose target accepts the variants of your `Routable` enum,
+/// checked at compile time, as well as external URLs.
+///
+/// The navigation item matching the current route, according to `active_match`, gets the `active` class,
+/// `aria-current="page"` and the `active_color` (defaults to `nav_item_color`).
 #[derive(PartialEq, Clone)]
 pub struct NavbarConfig {
     pub background_color: ColorScheme,
     pub header_color: HeaderColor,
     pub nav_header: Option<String>,
-    pub nav_items: Vec<String>,
-    pub nav_links: Vec<String>,
+    pub nav_items: Vec<NavItem>,
     pub nav_item_color: NavItemsColor,
     pub icon_color: IconColor,
     pub orientation: Option<Orientation>,
+    pub active_color: Option<NavItemsColor>,
+    pub active_match: ActiveMatch,
 }
 
 /// Configuration struct for the **`NavbarWithLogo`** component.
+///
+/// The active navigation item is highlighted like in the **`Navbar`** component.
 #[derive(PartialEq, Clone)]
 pub struct NavbarWithLogoConfig {
     pub background_color: ColorScheme,
-    pub nav_items: Vec<String>,
-    pub nav_links: Vec<String>,
+    pub nav_items: Vec<NavItem>,
     pub nav_item_color: NavItemsColor,
     pub icon_color: IconColor,
-    pub logo_url: String,
+    /// Where the logo links to: a variant of your `Routable` enum or a URL.
+    pub logo_url: NavigationTarget,
     pub logo_src: Asset,
     pub logo_alt: String,
     pub orientation: Option<Orientation>,
+    pub active_color: Option<NavItemsColor>,
+    pub active_match: ActiveMatch,
 }
 
-/// Configuration struct for the **`NavbarDropdown`** component.
+/// Configuration struct for the **`NavbarWithDropdowns`** component.
+///
+/// The active navigation item is highlighted like in the **`Navbar`** component.
 #[derive(PartialEq, Clone)]
 pub struct NavbarDropdownConfig {
     pub background_color: ColorScheme,
     pub nav_header: Option<String>,
     pub header_color: HeaderColor,
-    pub nav_items: Vec<String>,
-    pub nav_links: Vec<String>,
+    pub nav_items: Vec<NavItem>,
     pub nav_item_color: NavItemsColor,
     pub icon_color: IconColor,
     pub dropdowns: Vec<DropdownConfigNavBar>,
     pub orientation: Option<Orientation>,
+    pub active_color: Option<NavItemsColor>,
+    pub active_match: ActiveMatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(path: &str) -> NavigationTarget {
+        NavigationTarget::Internal(path.to_string())
+    }
+
+    #[test]
+    fn exact_match_only_matches_the_link() {
+        assert!(ActiveMatch::Exact.is_active("/about", &link("/about")));
+        assert!(!ActiveMatch::Exact.is_active("/about/team", &link("/about")));
+        assert!(!ActiveMatch::Exact.is_active("/", &link("/about")));
+        assert!(ActiveMatch::Exact.is_active("/", &link("/")));
+    }
+
+    #[test]
+    fn prefix_match_also_matches_nested_routes() {
+        assert!(ActiveMatch::Prefix.is_active("/blog", &link("/blog")));
+        assert!(ActiveMatch::Prefix.is_active("/blog/my-post", &link("/blog")));
+        assert!(!ActiveMatch::Prefix.is_active("/blogroll", &link("/blog")));
+        assert!(!ActiveMatch::Prefix.is_active("/blog", &link("/")));
+        assert!(ActiveMatch::Prefix.is_active("/", &link("/")));
+    }
+
+    #[test]
+    fn ignores_trailing_slashes() {
+        assert!(ActiveMatch::Exact.is_active("/about/", &link("/about")));
+        assert!(ActiveMatch::Exact.is_active("/about", &link("/about/")));
+        assert!(ActiveMatch::Prefix.is_active("/blog/my-post/", &link("/blog/")));
+        assert!(ActiveMatch::Exact.is_active("", &link("/")));
+    }
+
+    #[test]
+    fn ignores_query_strings_and_fragments() {
+        assert!(ActiveMatch::Exact.is_active("/search?q=freyr", &link("/search")));
+        assert!(ActiveMatch::Exact.is_active("/docs#install", &link("/docs")));
+        assert!(ActiveMatch::Prefix.is_active("/blog/my-post?page=2", &link("/blog")));
+        assert!(ActiveMatch::Exact.is_active("/?tab=1", &link("/")));
+    }
+
+    #[test]
+    fn from_pairs_builds_one_item_per_label() {
+        let items = NavItem::from_pairs(
+            vec!["Home".to_string(), "About".to_string()],
+            vec![link("/"), link("/about")],
+        )
+        .unwrap();
+        assert!(
+            items
+                == vec![
+                    NavItem::new("Home", link("/")),
+                    NavItem::new("About", link("/about"))
+                ]
+        );
+        assert!(items.iter().all(|item| item.validate().is_ok()));
+    }
+
+    #[test]
+    fn from_pairs_rejects_a_length_mismatch() {
+        let result = NavItem::from_pairs(
+            vec!["Home".to_string(), "About".to_string()],
+            vec![link("/")],
+        );
+        assert_eq!(
+            result.err(),
+            Some(NavItemError::LengthMismatch {
+                labels: 2,
+                targets: 1
+            })
+        );
+    }
+
+    #[test]
+    fn validate_rejects_empty_labels() {
+        assert_eq!(
+            NavItem::new("  ", link("/")).validate(),
+            Err(NavItemError::EmptyLabel)
+        );
+        let menu = NavItem::menu("Docs", vec![NavItem::new("", link("/docs"))]);
+        assert_eq!(menu.validate(), Err(NavItemError::EmptyLabel));
+    }
+
+    #[test]
+    fn validate_rejects_unreachable_items() {
+        let item = NavItem {
+            target: None,
+            ..NavItem::new("Home", link("/"))
+        };
+        assert_eq!(
+            item.validate(),
+            Err(NavItemError::MissingTarget {
+                label: "Home".to_string()
+            })
+        );
+        assert_eq!(item.disabled().validate(), Ok(()));
+
+        let menu = NavItem::new("Docs", link("/docs"))
+            .child(NavItem::new("Install", link("/docs/install")));
+        assert_eq!(
+            menu.validate(),
+            Err(NavItemError::TargetWithChildren {
+                label: "Docs".to_string()
+            })
+        );
+    }
 }
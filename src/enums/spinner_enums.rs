@@ -1,9 +1,12 @@
+use crate::enums::color_enums::Color;
 use dioxus::prelude::*;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct SpinnerProps {
     pub height: Option<String>,
     pub width: Option<String>,
-    pub spinner_color: Option<String>,
-    pub spinner_bg_color: Option<String>,
+    #[props(into)]
+    pub spinner_color: Option<Color>,
+    #[props(into)]
+    pub spinner_bg_color: Option<Color>,
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::enums::color_enums::Color;
 use dioxus::prelude::*;
 
 #[derive(PartialEq, Clone)]
@@ -5,25 +6,45 @@ pub enum TabsColor {
     Freyr,
     Black,
     Light,
-    Custom(&'static str),
+    Custom(Color),
 }
 
 impl TabsColor {
     pub fn to_css_class(&self) -> &'static str {
         match self {
-            TabsColor::Freyr => "tabs-freyr",
-            TabsColor::Black => "tabs-black",
-            TabsColor::Light => "tabs-light",
+            TabsColor::Freyr => "freyr-tabs-freyr",
+            TabsColor::Black => "freyr-tabs-black",
+            TabsColor::Light => "freyr-tabs-light",
             TabsColor::Custom(_) => "",
         }
     }
 }
 
+/// How the keyboard selects a tab. With `Automatic`, moving the focus with the arrow keys, Home and End
+/// selects the focused tab. With `Manual`, the focused tab is only selected with Enter or Space,
+/// which suits panels that are slow to display.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum TabsActivation {
+    #[default]
+    Automatic,
+    Manual,
+}
+
 #[derive(PartialEq, Clone, Props)]
 pub struct TabsProps {
     pub tabs_names: Vec<String>,
     pub custom_texts: Option<Vec<Element>>,
     pub custom_color: Option<TabsColor>,
+    #[props(default)]
+    pub activation: TabsActivation,
+    /// Index of the selected tab. Without it, the component keeps its own selection, starting at `default_index`.
+    pub selected: Option<Signal<usize>>,
+    pub default_index: Option<usize>,
+    /// Called with the index of the newly selected tab.
+    pub on_change: Option<EventHandler<usize>>,
+    /// Indexes of the tabs that cannot be selected.
+    #[props(default)]
+    pub disabled: Vec<usize>,
 }
 
 #[derive(PartialEq, Clone, Props)]
@@ -31,12 +52,27 @@ pub struct TabsSecondaryProps {
     pub tabs_names: Vec<String>,
     pub custom_texts: Option<Vec<Element>>,
     pub tab_max_width: Option<String>,
-    pub tab_header_hover: Option<String>,
+    #[props(into)]
+    pub tab_header_hover: Option<Color>,
     pub tabs_header_class: Option<String>,
-    pub header_bg_color: Option<String>,
-    pub header_text_color: Option<String>,
-    pub active_bg_color: Option<String>,
-    pub active_text_color: Option<String>,
+    #[props(into)]
+    pub header_bg_color: Option<Color>,
+    #[props(into)]
+    pub header_text_color: Option<Color>,
+    #[props(into)]
+    pub active_bg_color: Option<Color>,
+    #[props(into)]
+    pub active_text_color: Option<Color>,
     pub tab_radius: Option<String>,
     pub tab_shadow: Option<String>,
+    #[props(default)]
+    pub activation: TabsActivation,
+    /// Index of the selected tab. Without it, the component keeps its own selection, starting at `default_index`.
+    pub selected: Option<Signal<usize>>,
+    pub default_index: Option<usize>,
+    /// Called with the index of the newly selected tab.
+    pub on_change: Option<EventHandler<usize>>,
+    /// Indexes of the tabs that cannot be selected.
+    #[props(default)]
+    pub disabled: Vec<usize>,
 }
\ No newline at end of file
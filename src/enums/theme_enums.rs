@@ -0,0 +1,256 @@
+use crate::enums::color_enums::Color;
+use std::fmt;
+use std::str::FromStr;
+
+/// Colors shared by every freyr component.
+///
+/// `primary` is the "Freyr" color used by every `Freyr` variant of the component color enums,
+/// `dark` and `light` back the `Dark` and `Light` variants.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Palette {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub primary_muted: Color,
+    pub accent: Color,
+    pub dark: Color,
+    pub light: Color,
+    pub background: Color,
+    pub surface: Color,
+    pub text: Color,
+    pub muted: Color,
+    pub border: Color,
+    pub success: Color,
+    pub danger: Color,
+    pub warning: Color,
+    pub info: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            primary: "#3795BD".into(),
+            on_primary: "#FFFFFF".into(),
+            primary_muted: "#B4C8D1".into(),
+            accent: "#007BFF".into(),
+            dark: "#1E201E".into(),
+            light: "#F7F7F8".into(),
+            background: "#FFFFFF".into(),
+            surface: "#FFFFFF".into(),
+            text: "#1E201E".into(),
+            muted: "#6B7280".into(),
+            border: "#E5E7EB".into(),
+            success: "#28A745".into(),
+            danger: "#DC3545".into(),
+            warning: "#FFC107".into(),
+            info: "#17A2B8".into(),
+        }
+    }
+}
+
+impl Palette {
+    /// The palette used by `Theme::dark()`: dark backgrounds and surfaces with light text.
+    pub fn dark() -> Self {
+        Self {
+            primary_muted: "#2C4A57".into(),
+            accent: "#3D8BFD".into(),
+            background: "#121212".into(),
+            surface: "#1E201E".into(),
+            text: "#F7F7F8".into(),
+            muted: "#9CA3AF".into(),
+            border: "#374151".into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Font settings applied to the components.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Typography {
+    pub font_family: String,
+    pub font_size: String,
+    pub font_weight_bold: String,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            font_family: "inherit".to_string(),
+            font_size: "1rem".to_string(),
+            font_weight_bold: "600".to_string(),
+        }
+    }
+}
+
+/// Border radii, from small (buttons) to large (cards).
+#[derive(PartialEq, Clone, Debug)]
+pub struct Radii {
+    pub sm: String,
+    pub md: String,
+    pub lg: String,
+}
+
+impl Default for Radii {
+    fn default() -> Self {
+        Self {
+            sm: "5px".to_string(),
+            md: "8px".to_string(),
+            lg: "1rem".to_string(),
+        }
+    }
+}
+
+/// Spacing scale used for paddings and gaps.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Spacing {
+    pub xs: String,
+    pub sm: String,
+    pub md: String,
+    pub lg: String,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            xs: "0.25rem".to_string(),
+            sm: "0.5rem".to_string(),
+            md: "1rem".to_string(),
+            lg: "1.5rem".to_string(),
+        }
+    }
+}
+
+/// Box shadows, from subtle (cards) to strong (dialogs).
+#[derive(PartialEq, Clone, Debug)]
+pub struct Shadows {
+    pub sm: String,
+    pub md: String,
+    pub lg: String,
+}
+
+impl Default for Shadows {
+    fn default() -> Self {
+        Self {
+            sm: "0 1px 3px rgba(0, 0, 0, 0.12), 0 4px 6px rgba(0, 0, 0, 0.16)".to_string(),
+            md: "0px 10px 20px rgba(0, 0, 0, 0.1)".to_string(),
+            lg: "0 10px 25px rgba(0, 0, 0, 0.1)".to_string(),
+        }
+    }
+}
+
+/// The design tokens read by every freyr component, provided through the **`ThemeProvider`** component.
+///
+/// Start from `Theme::default()` and only override what you need:
+/// ```rust,ignore
+/// let theme = Theme {
+///     palette: Palette {
+///         primary: "#03346E".into(),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Theme {
+    pub palette: Palette,
+    pub typography: Typography,
+    pub radii: Radii,
+    pub spacing: Spacing,
+    pub shadows: Shadows,
+}
+
+impl Theme {
+    /// The default theme with the `Palette::dark()` colors, used by the **`ThemeProvider`** in dark mode.
+    pub fn dark() -> Self {
+        Self {
+            palette: Palette::dark(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the theme as CSS custom properties (`--freyr-…`), ready to be used in a `style` attribute.
+    pub fn to_css_variables(&self) -> String {
+        let Theme {
+            palette,
+            typography,
+            radii,
+            spacing,
+            shadows,
+        } = self;
+
+        let tokens: [(&str, &dyn fmt::Display); 28] = [
+            ("color-primary", &palette.primary),
+            ("color-on-primary", &palette.on_primary),
+            ("color-primary-muted", &palette.primary_muted),
+            ("color-accent", &palette.accent),
+            ("color-dark", &palette.dark),
+            ("color-light", &palette.light),
+            ("color-background", &palette.background),
+            ("color-surface", &palette.surface),
+            ("color-text", &palette.text),
+            ("color-muted", &palette.muted),
+            ("color-border", &palette.border),
+            ("color-success", &palette.success),
+            ("color-danger", &palette.danger),
+            ("color-warning", &palette.warning),
+            ("color-info", &palette.info),
+            ("font-family", &typography.font_family),
+            ("font-size", &typography.font_size),
+            ("font-weight-bold", &typography.font_weight_bold),
+            ("radius-sm", &radii.sm),
+            ("radius-md", &radii.md),
+            ("radius-lg", &radii.lg),
+            ("spacing-xs", &spacing.xs),
+            ("spacing-sm", &spacing.sm),
+            ("spacing-md", &spacing.md),
+            ("spacing-lg", &spacing.lg),
+            ("shadow-sm", &shadows.sm),
+            ("shadow-md", &shadows.md),
+            ("shadow-lg", &shadows.lg),
+        ];
+
+        tokens
+            .iter()
+            .map(|(name, value)| format!("--freyr-{name}: {value};"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The color mode of a **`ThemeProvider`**. `System` follows the `prefers-color-scheme` of the browser.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ColorMode {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl ColorMode {
+    /// Returns the value stored in localStorage for this mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorMode::Light => "light",
+            ColorMode::Dark => "dark",
+            ColorMode::System => "system",
+        }
+    }
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "light" => Ok(ColorMode::Light),
+            "dark" => Ok(ColorMode::Dark),
+            "system" => Ok(ColorMode::System),
+            other => Err(format!("unknown color mode `{other}`")),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Variant of a toast, setting its color and icon. The colors come from the `success`, `danger`,
+/// `info` and `warning` colors of the theme palette, like `ButtonColor::Success` and `ButtonColor::Danger`.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ToastVariant {
+    Success,
+    Error,
+    #[default]
+    Info,
+    Warning,
+}
+
+impl ToastVariant {
+    /// Returns the CSS class of the variant.
+    pub fn as_css_class(&self) -> &'static str {
+        match self {
+            ToastVariant::Success => "freyr-toast-success",
+            ToastVariant::Error => "freyr-toast-error",
+            ToastVariant::Info => "freyr-toast-info",
+            ToastVariant::Warning => "freyr-toast-warning",
+        }
+    }
+
+    /// Errors and warnings interrupt screen readers, the other variants wait until they are idle.
+    pub(crate) fn role(&self) -> &'static str {
+        match self {
+            ToastVariant::Error | ToastVariant::Warning => "alert",
+            ToastVariant::Success | ToastVariant::Info => "status",
+        }
+    }
+}
+
+/// Where the **`ToastProvider`** stacks its toasts on the screen.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl ToastPosition {
+    /// Returns the CSS class of the position.
+    pub fn as_css_class(&self) -> &'static str {
+        match self {
+            ToastPosition::TopLeft => "freyr-toast-top-left",
+            ToastPosition::TopCenter => "freyr-toast-top-center",
+            ToastPosition::TopRight => "freyr-toast-top-right",
+            ToastPosition::BottomLeft => "freyr-toast-bottom-left",
+            ToastPosition::BottomCenter => "freyr-toast-bottom-center",
+            ToastPosition::BottomRight => "freyr-toast-bottom-right",
+        }
+    }
+
+    /// Whether the newest toasts are displayed at the bottom of the stack.
+    pub(crate) fn is_bottom(&self) -> bool {
+        matches!(
+            self,
+            ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight
+        )
+    }
+}
+
+/// Identifier of a toast, returned when it is pushed so it can be dismissed later.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct ToastId(pub(crate) usize);
+
+/// Called when the action button of a toast is clicked.
+pub type ToastActionHandler = Rc<dyn Fn()>;
+
+/// Button displayed in a toast, e.g. "Undo". Clicking it runs `on_click` and dismisses the toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: ToastActionHandler,
+}
+
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.on_click, &other.on_click)
+    }
+}
+
+/// A notification pushed through `use_toast()`.
+///
+/// It is dismissed after the `duration` of the **`ToastProvider`** unless `duration` or `persistent` are set,
+/// and can be closed manually unless `dismissible` is `false`.
+///
+/// ```rust,ignore
+/// Toast::success("Message sent")
+///     .title("Done")
+///     .duration(Duration::from_secs(8))
+///     .action("Undo", move || undo());
+/// ```
+#[derive(PartialEq, Clone)]
+pub struct Toast {
+    pub variant: ToastVariant,
+    pub title: Option<String>,
+    pub message: String,
+    /// `None` uses the duration of the provider.
+    pub duration: Option<Duration>,
+    pub persistent: bool,
+    pub dismissible: bool,
+    pub action: Option<ToastAction>,
+}
+
+impl Toast {
+    pub fn new(variant: ToastVariant, message: impl Into<String>) -> Self {
+        Self {
+            variant,
+            title: None,
+            message: message.into(),
+            duration: None,
+            persistent: false,
+            dismissible: true,
+            action: None,
+        }
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Success, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Error, message)
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Info, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastVariant::Warning, message)
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Dismisses the toast after `duration` instead of the duration of the provider.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keeps the toast until it is dismissed.
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Hides the close button. Only use it with toasts that are dismissed automatically or through their action.
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    pub fn action(mut self, label: impl Into<String>, on_click: impl Fn() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            on_click: Rc::new(on_click),
+        });
+        self
+    }
+}
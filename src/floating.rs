@@ -0,0 +1,299 @@
+use crate::enums::floating_enums::{Align, Placement, Side};
+use crate::scripts::floating_script::FLOATING_SCRIPT;
+use crate::scripts::{eval, js_string};
+use dioxus::core::Task;
+use dioxus::prelude::*;
+
+/// Minimum distance kept between a floating element and the edges of the viewport.
+const VIEWPORT_PADDING: f64 = 8.0;
+
+/// Minimum distance kept between the arrow and the corners of the floating element.
+const ARROW_PADDING: f64 = 12.0;
+
+/// A rectangle in viewport coordinates, in pixels.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub(crate) struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// A rectangle of which only the size matters.
+    pub fn sized(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+}
+
+/// Where to render a floating element: its top-left corner in viewport coordinates, the placement
+/// actually used after flipping, and the offset of the arrow along the side of the floating element.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) struct FloatingPosition {
+    pub x: f64,
+    pub y: f64,
+    pub placement: Placement,
+    pub arrow: f64,
+}
+
+impl FloatingPosition {
+    /// Inline style of the floating element.
+    pub fn style(&self) -> String {
+        format!("position: fixed; left: {}px; top: {}px;", self.x, self.y)
+    }
+
+    /// Inline style of the arrow of the floating element.
+    pub fn arrow_style(&self) -> String {
+        match self.placement.side() {
+            Side::Top | Side::Bottom => format!("left: {}px;", self.arrow),
+            Side::Left | Side::Right => format!("top: {}px;", self.arrow),
+        }
+    }
+}
+
+/// Inline style of a floating element that is not measured yet: rendered, but invisible.
+pub(crate) const UNMEASURED_STYLE: &str = "position: fixed; left: 0; top: 0; visibility: hidden;";
+
+/// Places `floating` at `placement` around `anchor`, `offset` pixels away from it.
+fn place(anchor: Rect, floating: Rect, placement: Placement, offset: f64) -> (f64, f64) {
+    let cross = |start: f64, anchor_size: f64, floating_size: f64| match placement.align() {
+        Align::Start => start,
+        Align::Center => start + (anchor_size - floating_size) / 2.0,
+        Align::End => start + anchor_size - floating_size,
+    };
+
+    match placement.side() {
+        Side::Top => (
+            cross(anchor.x, anchor.width, floating.width),
+            anchor.y - floating.height - offset,
+        ),
+        Side::Bottom => (
+            cross(anchor.x, anchor.width, floating.width),
+            anchor.y + anchor.height + offset,
+        ),
+        Side::Left => (
+            anchor.x - floating.width - offset,
+            cross(anchor.y, anchor.height, floating.height),
+        ),
+        Side::Right => (
+            anchor.x + anchor.width + offset,
+            cross(anchor.y, anchor.height, floating.height),
+        ),
+    }
+}
+
+/// Returns by how many pixels the floating element at `(x, y)` overflows the viewport on its side.
+fn overflow(x: f64, y: f64, floating: Rect, viewport: Rect, side: Side) -> f64 {
+    match side {
+        Side::Top => VIEWPORT_PADDING - y,
+        Side::Bottom => y + floating.height - (viewport.height - VIEWPORT_PADDING),
+        Side::Left => VIEWPORT_PADDING - x,
+        Side::Right => x + floating.width - (viewport.width - VIEWPORT_PADDING),
+    }
+}
+
+/// Keeps `position` inside `[VIEWPORT_PADDING, viewport_size - size - VIEWPORT_PADDING]` when it fits.
+fn shift(position: f64, size: f64, viewport_size: f64) -> f64 {
+    let max = (viewport_size - size - VIEWPORT_PADDING).max(VIEWPORT_PADDING);
+    position.clamp(VIEWPORT_PADDING, max)
+}
+
+/// Computes the position of a floating element of the size of `floating` around `anchor`.
+///
+/// The floating element flips to the opposite side when it overflows the viewport and the opposite
+/// side has more room, then shifts along its side to stay in the viewport.
+/// The arrow keeps pointing at the center of the anchor.
+pub(crate) fn compute_position(
+    anchor: Rect,
+    floating: Rect,
+    viewport: Rect,
+    placement: Placement,
+    offset: f64,
+) -> FloatingPosition {
+    let (mut x, mut y) = place(anchor, floating, placement, offset);
+    let mut placement = placement;
+
+    let current = overflow(x, y, floating, viewport, placement.side());
+    if current > 0.0 {
+        let flipped = placement.flipped();
+        let (flipped_x, flipped_y) = place(anchor, floating, flipped, offset);
+        if overflow(flipped_x, flipped_y, floating, viewport, flipped.side()) < current {
+            (x, y, placement) = (flipped_x, flipped_y, flipped);
+        }
+    }
+
+    let arrow = match placement.side() {
+        Side::Top | Side::Bottom => {
+            x = shift(x, floating.width, viewport.width);
+            anchor.x + anchor.width / 2.0 - x
+        }
+        Side::Left | Side::Right => {
+            y = shift(y, floating.height, viewport.height);
+            anchor.y + anchor.height / 2.0 - y
+        }
+    };
+    let length = match placement.side() {
+        Side::Top | Side::Bottom => floating.width,
+        Side::Left | Side::Right => floating.height,
+    };
+    let arrow = arrow.clamp(
+        ARROW_PADDING.min(length / 2.0),
+        (length - ARROW_PADDING).max(length / 2.0),
+    );
+
+    FloatingPosition {
+        x,
+        y,
+        placement,
+        arrow,
+    }
+}
+
+/// Positions the element with the id `floating_id` around the element with the id `anchor_id` while `open` is `true`,
+/// following scroll and resize. Returns `None` until both elements are measured.
+///
+/// The floating element should be rendered with `UNMEASURED_STYLE` until a position is returned.
+pub(crate) fn use_floating(
+    anchor_id: String,
+    floating_id: String,
+    open: bool,
+    placement: Placement,
+    offset: f64,
+) -> Option<FloatingPosition> {
+    let mut position = use_signal(|| None::<FloatingPosition>);
+    let mut task = use_hook(|| CopyValue::new(None::<Task>));
+
+    use_effect(use_reactive!(|open, placement, offset| {
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        position.set(None);
+        if !open {
+            return;
+        }
+
+        let script = FLOATING_SCRIPT
+            .replace("{anchor_id}", &js_string(&anchor_id))
+            .replace("{floating_id}", &js_string(&floating_id));
+        task.set(Some(spawn(async move {
+            let Some(mut listener) = eval(&script) else {
+                return;
+            };
+            while let Ok(measures) = listener.recv::<[f64; 8]>().await {
+                // The anchor, then the size of the floating element and of the viewport
+                let anchor = Rect {
+                    x: measures[0],
+                    y: measures[1],
+                    width: measures[2],
+                    height: measures[3],
+                };
+                let floating = Rect::sized(measures[4], measures[5]);
+                let viewport = Rect::sized(measures[6], measures[7]);
+                position.set(Some(compute_position(
+                    anchor, floating, viewport, placement, offset,
+                )));
+            }
+        })));
+    }));
+
+    position()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 1000.0,
+        height: 800.0,
+    };
+
+    fn anchor(x: f64, y: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width: 100.0,
+            height: 40.0,
+        }
+    }
+
+    fn position(anchor: Rect, placement: Placement) -> FloatingPosition {
+        compute_position(anchor, Rect::sized(80.0, 30.0), VIEWPORT, placement, 8.0)
+    }
+
+    #[test]
+    fn places_around_the_anchor() {
+        let cases = [
+            (Placement::Top, 410.0, 262.0),
+            (Placement::TopStart, 400.0, 262.0),
+            (Placement::TopEnd, 420.0, 262.0),
+            (Placement::Bottom, 410.0, 348.0),
+            (Placement::BottomStart, 400.0, 348.0),
+            (Placement::BottomEnd, 420.0, 348.0),
+            (Placement::Left, 312.0, 305.0),
+            (Placement::LeftStart, 312.0, 300.0),
+            (Placement::LeftEnd, 312.0, 310.0),
+            (Placement::Right, 508.0, 305.0),
+            (Placement::RightStart, 508.0, 300.0),
+            (Placement::RightEnd, 508.0, 310.0),
+        ];
+        for (placement, x, y) in cases {
+            let position = position(anchor(400.0, 300.0), placement);
+            assert_eq!((position.x, position.y), (x, y), "{placement:?}");
+            assert_eq!(position.placement, placement);
+        }
+    }
+
+    #[test]
+    fn points_the_arrow_at_the_anchor() {
+        assert_eq!(position(anchor(400.0, 300.0), Placement::Top).arrow, 40.0);
+        assert_eq!(position(anchor(400.0, 300.0), Placement::Right).arrow, 15.0);
+    }
+
+    #[test]
+    fn flips_at_the_viewport_edges() {
+        let top = position(anchor(400.0, 10.0), Placement::Top);
+        assert_eq!((top.placement, top.y), (Placement::Bottom, 58.0));
+
+        let bottom = position(anchor(400.0, 770.0), Placement::BottomStart);
+        assert_eq!((bottom.placement, bottom.y), (Placement::TopStart, 732.0));
+
+        let left = position(anchor(5.0, 300.0), Placement::Left);
+        assert_eq!((left.placement, left.x), (Placement::Right, 113.0));
+
+        let right = position(anchor(950.0, 300.0), Placement::RightEnd);
+        assert_eq!((right.placement, right.x), (Placement::LeftEnd, 862.0));
+    }
+
+    #[test]
+    fn keeps_the_side_when_the_opposite_one_has_no_more_room() {
+        let viewport = Rect::sized(1000.0, 100.0);
+        let position = compute_position(
+            anchor(400.0, 30.0),
+            Rect::sized(80.0, 30.0),
+            viewport,
+            Placement::Top,
+            8.0,
+        );
+        assert_eq!(position.placement, Placement::Top);
+    }
+
+    #[test]
+    fn shifts_into_the_viewport() {
+        let anchor = Rect {
+            x: 0.0,
+            y: 300.0,
+            width: 20.0,
+            height: 40.0,
+        };
+        let position = position(anchor, Placement::Bottom);
+        assert_eq!(position.x, VIEWPORT_PADDING);
+        assert_eq!(position.arrow, ARROW_PADDING);
+    }
+}
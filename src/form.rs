@@ -0,0 +1,442 @@
+use crate::enums::form_enums::{AsyncValidator, SubmitHandler, ValidationFuture, Validator};
+use dioxus::prelude::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Type-erased operations the form runs on every registered field.
+#[derive(Clone)]
+struct FieldEntry {
+    id: usize,
+    touch: Rc<dyn Fn()>,
+    reset: Rc<dyn Fn()>,
+    is_dirty: Rc<dyn Fn() -> bool>,
+    is_valid: Rc<dyn Fn() -> bool>,
+    validate: Rc<dyn Fn() -> Pin<Box<dyn Future<Output = bool>>>>,
+}
+
+/// Handle to the state of a form, returned by `use_form()`.
+///
+/// Its fields are created with `use_form_field`, and it is submitted through the **`Form`** component
+/// or `UseForm::submit`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseForm {
+    fields: CopyValue<Vec<FieldEntry>>,
+    next_id: CopyValue<usize>,
+    submitting: Signal<bool>,
+    submitted: Signal<bool>,
+}
+
+impl UseForm {
+    /// Whether the form is being validated or its submit handler is running.
+    pub fn is_submitting(&self) -> bool {
+        (self.submitting)()
+    }
+
+    /// Whether the form has been submitted at least once. Every field error is displayed from then on.
+    pub fn is_submitted(&self) -> bool {
+        (self.submitted)()
+    }
+
+    /// Whether a field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.entries().iter().any(|entry| (entry.is_dirty)())
+    }
+
+    /// Whether every field passes its validators. Asynchronous validators only count once they ran.
+    pub fn is_valid(&self) -> bool {
+        self.entries().iter().all(|entry| (entry.is_valid)())
+    }
+
+    /// Restores the initial value of every field and forgets that they were touched or submitted.
+    pub fn reset(&mut self) {
+        for entry in self.entries() {
+            (entry.reset)();
+        }
+        self.submitted.set(false);
+    }
+
+    /// Touches and validates every field, then runs `on_submit` when they are all valid.
+    /// The form is submitting from the start of the validation until the future returned by `on_submit`
+    /// completes, and submitting it again meanwhile does nothing.
+    pub fn submit<F, Fut>(&self, on_submit: F)
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let mut form = *self;
+        if *form.submitting.peek() {
+            return;
+        }
+        form.submitting.set(true);
+
+        spawn(async move {
+            form.submitted.set(true);
+            let entries = form.entries();
+            let mut valid = true;
+            for entry in entries {
+                (entry.touch)();
+                valid &= (entry.validate)().await;
+            }
+
+            if valid {
+                on_submit().await;
+            }
+            form.submitting.set(false);
+        });
+    }
+
+    fn entries(&self) -> Vec<FieldEntry> {
+        self.fields.read().clone()
+    }
+}
+
+/// Creates the state of a form: its fields, whether it is dirty or valid, and its submission.
+///
+/// ```rust,ignore
+/// #[component]
+/// fn SignUp() -> Element {
+///     let form = use_form();
+///     let email = use_form_field(form, String::new)
+///         .validate(|email: &String| match email.contains('@') {
+///             true => Ok(()),
+///             false => Err("Enter a valid email".to_string()),
+///         })
+///         .validate_async(|email: String| async move { api::is_available(&email).await });
+///     let accepted = use_form_field(form, || false).validate(|accepted: &bool| match accepted {
+///         true => Ok(()),
+///         false => Err("Please accept the terms".to_string()),
+///     });
+///
+///     rsx! {
+///         Form { form, on_submit: move || async move { api::sign_up(email.value()).await },
+///             TextInput {
+///                 value: email.value,
+///                 label: "Email",
+///                 error: email.error(),
+///                 onblur: move |_| email.touch(),
+///             }
+///             Checkbox {
+///                 checked: accepted.value,
+///                 label: "I accept the terms",
+///                 error: accepted.error(),
+///                 onblur: move |_| accepted.touch(),
+///             }
+///             EventButton {
+///                 color: ButtonColor::Freyr,
+///                 label: "Sign up",
+///                 button_type: "submit",
+///                 loading: form.is_submitting(),
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_form() -> UseForm {
+    let fields = use_hook(|| CopyValue::new(Vec::new()));
+    let next_id = use_hook(|| CopyValue::new(0));
+    let submitting = use_signal(|| false);
+    let submitted = use_signal(|| false);
+
+    UseForm {
+        fields,
+        next_id,
+        submitting,
+        submitted,
+    }
+}
+
+/// A field of a form created by `use_form_field`. Bind its `value` signal to a form input.
+pub struct FormField<T: 'static> {
+    pub value: Signal<T>,
+    initial: CopyValue<T>,
+    touched: Signal<bool>,
+    validating: Signal<bool>,
+    async_error: Signal<Option<String>>,
+    validators: CopyValue<Vec<Validator<T>>>,
+    async_validators: CopyValue<Vec<AsyncValidator<T>>>,
+    form: UseForm,
+}
+
+impl<T> Clone for FormField<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FormField<T> {}
+
+impl<T> PartialEq for FormField<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> FormField<T> {
+    /// Adds a validator, run whenever the value changes.
+    pub fn validate(mut self, validator: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        self.validators.write().push(Rc::new(validator));
+        self
+    }
+
+    /// Adds an asynchronous validator, run when the field is touched, when its value changes
+    /// after that, and on submit. It only runs when the other validators pass.
+    pub fn validate_async<Fut>(mut self, validator: impl Fn(T) -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        let validator: AsyncValidator<T> =
+            Rc::new(move |value| Box::pin(validator(value)) as ValidationFuture);
+        self.async_validators.write().push(validator);
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> T {
+        (self.value)()
+    }
+
+    /// Sets the value.
+    pub fn set(&mut self, value: T) {
+        self.value.set(value);
+    }
+
+    /// Whether the value differs from the initial one.
+    pub fn is_dirty(&self) -> bool {
+        *self.value.read() != *self.initial.read()
+    }
+
+    /// Whether the user left the field, or the form was submitted.
+    pub fn is_touched(&self) -> bool {
+        (self.touched)()
+    }
+
+    /// Whether an asynchronous validator is running.
+    pub fn is_validating(&self) -> bool {
+        (self.validating)()
+    }
+
+    /// Marks the field as touched, usually when it loses focus, and runs its asynchronous validators.
+    pub fn touch(&mut self) {
+        self.touched.set(true);
+        let field = *self;
+        spawn(async move {
+            field.run_validators().await;
+        });
+    }
+
+    /// Returns the error of the field, whether or not it should be displayed yet.
+    pub fn validation_error(&self) -> Option<String> {
+        let value = self.value.read();
+        self.validators
+            .read()
+            .iter()
+            .find_map(|validator| validator(&value).err())
+            .or_else(|| (self.async_error)())
+    }
+
+    /// Returns the error to display: only once the field is touched or the form submitted.
+    pub fn error(&self) -> Option<String> {
+        if self.is_touched() || self.form.is_submitted() {
+            self.validation_error()
+        } else {
+            None
+        }
+    }
+
+    /// Restores the initial value and forgets that the field was touched.
+    pub fn reset(&mut self) {
+        self.value.set(self.initial.read().clone());
+        self.touched.set(false);
+        self.async_error.set(None);
+    }
+
+    /// Runs every validator, returning whether the current value is valid.
+    async fn run_validators(self) -> bool {
+        let (mut validating, mut async_error) = (self.validating, self.async_error);
+        let value = self.value.peek().clone();
+        let sync_valid = self
+            .validators
+            .read()
+            .iter()
+            .all(|validator| validator(&value).is_ok());
+        if !sync_valid {
+            async_error.set(None);
+            return false;
+        }
+
+        let async_validators = self.async_validators.read().clone();
+        if async_validators.is_empty() {
+            return true;
+        }
+
+        validating.set(true);
+        let mut result = Ok(());
+        for validator in async_validators {
+            result = validator(value.clone()).await;
+            if result.is_err() {
+                break;
+            }
+        }
+        validating.set(false);
+
+        // A newer value is being validated, this result is outdated
+        if *self.value.peek() != value {
+            return false;
+        }
+        let valid = result.is_ok();
+        async_error.set(result.err());
+        valid
+    }
+}
+
+/// Creates a field of the `form`, starting with the value returned by `initial`.
+/// Chain `validate` and `validate_async` to add validators, see `use_form()`.
+pub fn use_form_field<T: Clone + PartialEq + 'static>(
+    form: UseForm,
+    initial: impl FnOnce() -> T,
+) -> FormField<T> {
+    let value = use_signal(initial);
+    let initial = use_hook(|| CopyValue::new(value.peek().clone()));
+    let touched = use_signal(|| false);
+    let validating = use_signal(|| false);
+    let mut async_error = use_signal(|| None::<String>);
+    let mut validators = use_hook(|| CopyValue::new(Vec::new()));
+    let mut async_validators = use_hook(|| CopyValue::new(Vec::new()));
+
+    let field = FormField {
+        value,
+        initial,
+        touched,
+        validating,
+        async_error,
+        validators,
+        async_validators,
+        form,
+    };
+
+    // The validators are added again on every render, so they always capture the latest state
+    validators.write().clear();
+    async_validators.write().clear();
+
+    let id = use_hook(move || {
+        let mut next_id = form.next_id;
+        let id = *next_id.read();
+        next_id.set(id + 1);
+
+        let mut fields = form.fields;
+        fields.write().push(FieldEntry {
+            id,
+            touch: Rc::new(move || {
+                let mut touched = field.touched;
+                touched.set(true);
+            }),
+            reset: Rc::new(move || {
+                let mut field = field;
+                field.reset();
+            }),
+            is_dirty: Rc::new(move || field.is_dirty()),
+            is_valid: Rc::new(move || field.validation_error().is_none()),
+            validate: Rc::new(move || Box::pin(field.run_validators())),
+        });
+        id
+    });
+
+    use_drop(move || {
+        let mut fields = form.fields;
+        fields.write().retain(|entry| entry.id != id);
+    });
+
+    // Revalidate a touched field whenever its value changes
+    use_effect(move || {
+        value.read();
+        if *touched.peek() {
+            spawn(async move {
+                field.run_validators().await;
+            });
+        } else {
+            async_error.set(None);
+        }
+    });
+
+    field
+}
+
+/// A `form` element submitting the `form` created by `use_form()`: on submit, every field is touched
+/// and validated, and `on_submit` only runs when they are all valid. See `use_form()` for an example.
+#[component]
+pub fn Form(
+    form: UseForm,
+    #[props(into)] on_submit: SubmitHandler,
+    class: Option<String>,
+    children: Element,
+) -> Element {
+    rsx! {
+        form {
+            class,
+            novalidate: true,
+            aria_busy: "{form.is_submitting()}",
+            onsubmit: move |event| {
+                event.prevent_default();
+                let on_submit = on_submit.clone();
+                form.submit(move || on_submit.call());
+            },
+            {children}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::task::{Context, Waker};
+
+    thread_local! {
+        static FORM: Cell<Option<UseForm>> = const { Cell::new(None) };
+        static VALIDATIONS: Cell<usize> = const { Cell::new(0) };
+        static SUBMITS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn PendingForm() -> Element {
+        let form = use_form();
+        // The asynchronous validator never resolves, so the validation stays pending
+        use_form_field(form, String::new).validate_async(|_: String| {
+            VALIDATIONS.set(VALIDATIONS.get() + 1);
+            std::future::pending::<Result<(), String>>()
+        });
+        FORM.set(Some(form));
+        rsx! {}
+    }
+
+    /// Runs the tasks of the `dom` that are ready, without waiting for the pending ones.
+    fn run_ready_tasks(dom: &mut VirtualDom) {
+        let mut work = std::pin::pin!(dom.wait_for_work());
+        let _ = work.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+    }
+
+    fn submit(dom: &VirtualDom) {
+        let form = FORM.get().unwrap();
+        dom.in_scope(ScopeId::APP, || {
+            form.submit(|| async { SUBMITS.set(SUBMITS.get() + 1) })
+        });
+    }
+
+    #[test]
+    fn submit_is_ignored_while_validation_is_pending() {
+        let mut dom = VirtualDom::new(PendingForm);
+        dom.rebuild_in_place();
+        run_ready_tasks(&mut dom);
+
+        submit(&dom);
+        run_ready_tasks(&mut dom);
+        assert_eq!(VALIDATIONS.get(), 1);
+        assert!(dom.in_scope(ScopeId::APP, || FORM.get().unwrap().is_submitting()));
+
+        submit(&dom);
+        run_ready_tasks(&mut dom);
+        assert_eq!(VALIDATIONS.get(), 1);
+        assert_eq!(SUBMITS.get(), 0);
+    }
+}
@@ -0,0 +1,456 @@
+use crate::assets::input_styles::INPUT_STYLES;
+use crate::enums::input_enums::{
+    CheckboxProps, InputSize, RadioProps, SelectProps, TextAreaProps, TextInputProps,
+};
+use crate::styles::StyleOnce;
+use crate::unique_id::use_unique_id;
+use dioxus::prelude::*;
+
+/// Returns the classes of the wrapper shared by every form control.
+fn field_class(size: InputSize, disabled: bool, invalid: bool, class: Option<String>) -> String {
+    let mut classes = format!("freyr-form-field {}", size.as_css_class());
+    if disabled {
+        classes.push_str(" disabled");
+    }
+    if invalid {
+        classes.push_str(" invalid");
+    }
+    if let Some(class) = class {
+        classes.push(' ');
+        classes.push_str(&class);
+    }
+    classes
+}
+
+/// Returns the id of the message describing the control, if any.
+fn described_by(id: &str, helper_text: &Option<String>, error: &Option<String>) -> Option<String> {
+    if error.is_some() {
+        Some(format!("{id}-error"))
+    } else {
+        helper_text.as_ref().map(|_| format!("{id}-helper"))
+    }
+}
+
+/// The error of a control, or its helper text when it has no error.
+#[component]
+fn FieldMessages(id: String, helper_text: Option<String>, error: Option<String>) -> Element {
+    rsx! {
+        if let Some(error) = error {
+            p { id: "{id}-error", class: "freyr-form-field-error", role: "alert", "{error}" }
+        } else if let Some(helper_text) = helper_text {
+            p { id: "{id}-helper", class: "freyr-form-field-helper", "{helper_text}" }
+        }
+    }
+}
+
+/// The label of a text control, with a marker when the control is required.
+#[component]
+fn FieldLabel(id: String, label: Option<String>, required: bool) -> Element {
+    rsx! {
+        if let Some(label) = label {
+            label { class: "freyr-form-field-label", r#for: "{id}",
+                "{label}"
+                if required {
+                    span { class: "freyr-form-field-required", aria_hidden: "true", " *" }
+                }
+            }
+        }
+    }
+}
+
+/// Single-line text input bound to a `Signal<String>`.
+///
+/// ```rust,ignore
+/// let email = use_signal(String::new);
+/// let error = (!email().contains('@')).then(|| "Enter a valid email".to_string());
+///
+/// rsx! {
+///     TextInput {
+///         value: email,
+///         label: "Email",
+///         input_type: "email",
+///         placeholder: "you@example.com",
+///         helper_text: "We never share it.",
+///         error,
+///         required: true,
+///     }
+/// }
+/// ```
+#[component]
+pub fn TextInput(props: TextInputProps) -> Element {
+    let TextInputProps {
+        mut value,
+        label,
+        placeholder,
+        helper_text,
+        error,
+        input_type,
+        disabled,
+        readonly,
+        required,
+        size,
+        id,
+        class,
+        onblur,
+    } = props;
+    let generated_id = use_unique_id("input");
+    let id = id.unwrap_or(generated_id);
+    let described_by = described_by(&id, &helper_text, &error);
+    let invalid = error.is_some();
+    let field_class = field_class(size, disabled, invalid, class);
+    let input_type = input_type.unwrap_or_else(|| "text".to_string());
+
+    rsx! {
+        StyleOnce { css: INPUT_STYLES }
+
+        div { class: "{field_class}",
+            FieldLabel { id: id.clone(), label, required }
+            input {
+                id: "{id}",
+                class: "freyr-form-field-control",
+                r#type: "{input_type}",
+                value: "{value}",
+                placeholder,
+                disabled,
+                readonly,
+                required,
+                aria_invalid: "{invalid}",
+                aria_describedby: described_by,
+                oninput: move |event| value.set(event.value()),
+                onblur: move |event| {
+                    if let Some(onblur) = onblur {
+                        onblur.call(event);
+                    }
+                },
+            }
+            FieldMessages { id: id.clone(), helper_text, error }
+        }
+    }
+}
+
+/// Multi-line text input bound to a `Signal<String>`, taking the same options as the **`TextInput`**.
+///
+/// ```rust,ignore
+/// let message = use_signal(String::new);
+///
+/// rsx! {
+///     TextArea { value: message, label: "Message", rows: 6 }
+/// }
+/// ```
+#[component]
+pub fn TextArea(props: TextAreaProps) -> Element {
+    let TextAreaProps {
+        mut value,
+        label,
+        placeholder,
+        helper_text,
+        error,
+        rows,
+        disabled,
+        readonly,
+        required,
+        size,
+        id,
+        class,
+        onblur,
+    } = props;
+    let generated_id = use_unique_id("input");
+    let id = id.unwrap_or(generated_id);
+    let described_by = described_by(&id, &helper_text, &error);
+    let invalid = error.is_some();
+    let field_class = field_class(size, disabled, invalid, class);
+
+    rsx! {
+        StyleOnce { css: INPUT_STYLES }
+
+        div { class: "{field_class}",
+            FieldLabel { id: id.clone(), label, required }
+            textarea {
+                id: "{id}",
+                class: "freyr-form-field-control",
+                rows: "{rows.unwrap_or(4)}",
+                value: "{value}",
+                placeholder,
+                disabled,
+                readonly,
+                required,
+                aria_invalid: "{invalid}",
+                aria_describedby: described_by,
+                oninput: move |event| value.set(event.value()),
+                onblur: move |event| {
+                    if let Some(onblur) = onblur {
+                        onblur.call(event);
+                    }
+                },
+            }
+            FieldMessages { id: id.clone(), helper_text, error }
+        }
+    }
+}
+
+/// Checkbox bound to a `Signal<bool>`.
+///
+/// ```rust,ignore
+/// let accepted = use_signal(|| false);
+///
+/// rsx! {
+///     Checkbox { checked: accepted, label: "I accept the terms" }
+/// }
+/// ```
+#[component]
+pub fn Checkbox(props: CheckboxProps) -> Element {
+    rsx! {
+        CheckControl { checkbox: props, switch: false }
+    }
+}
+
+/// On/off switch bound to a `Signal<bool>`, taking the same options as the **`Checkbox`**.
+///
+/// ```rust,ignore
+/// let notifications = use_signal(|| true);
+///
+/// rsx! {
+///     Switch { checked: notifications, label: "Email notifications" }
+/// }
+/// ```
+#[component]
+pub fn Switch(props: CheckboxProps) -> Element {
+    rsx! {
+        CheckControl { checkbox: props, switch: true }
+    }
+}
+
+/// Shared markup of the **`Checkbox`** and **`Switch`** components.
+#[component]
+fn CheckControl(checkbox: CheckboxProps, switch: bool) -> Element {
+    let CheckboxProps {
+        mut checked,
+        label,
+        helper_text,
+        error,
+        disabled,
+        readonly,
+        size,
+        id,
+        class,
+        onblur,
+    } = checkbox;
+    let generated_id = use_unique_id("input");
+    let id = id.unwrap_or(generated_id);
+    let described_by = described_by(&id, &helper_text, &error);
+    let invalid = error.is_some();
+    let field_class = field_class(size, disabled, invalid, class);
+
+    rsx! {
+        StyleOnce { css: INPUT_STYLES }
+
+        div { class: "{field_class}",
+            label { class: if switch { "freyr-form-check freyr-form-switch" } else { "freyr-form-check" },
+                input {
+                    id: "{id}",
+                    class: "freyr-form-check-input",
+                    r#type: "checkbox",
+                    role: if switch { "switch" },
+                    checked: checked(),
+                    disabled,
+                    aria_readonly: readonly.then_some("true"),
+                    aria_invalid: "{invalid}",
+                    aria_describedby: described_by,
+                    onclick: move |event| {
+                        if readonly {
+                            event.prevent_default();
+                        }
+                    },
+                    onchange: move |event| {
+                        if !readonly {
+                            checked.set(event.checked());
+                        }
+                    },
+                    onblur: move |event| {
+                        if let Some(onblur) = onblur {
+                            onblur.call(event);
+                        }
+                    },
+                }
+                if switch {
+                    span { class: "freyr-form-switch-track", aria_hidden: "true" }
+                }
+                span { "{label}" }
+            }
+            FieldMessages { id: id.clone(), helper_text, error }
+        }
+    }
+}
+
+/// Group of radio buttons bound to a `Signal<T>`, set to the value of the chosen option.
+///
+/// ```rust,ignore
+/// #[derive(Clone, PartialEq)]
+/// enum Plan {
+///     Free,
+///     Pro,
+/// }
+///
+/// let plan = use_signal(|| Plan::Free);
+///
+/// rsx! {
+///     Radio {
+///         value: plan,
+///         label: "Plan",
+///         options: vec![SelectOption::new("Free", Plan::Free), SelectOption::new("Pro", Plan::Pro)],
+///         inline: true,
+///     }
+/// }
+/// ```
+#[component]
+pub fn Radio<T: Clone + PartialEq + 'static>(props: RadioProps<T>) -> Element {
+    let RadioProps {
+        mut value,
+        options,
+        label,
+        helper_text,
+        error,
+        inline,
+        disabled,
+        readonly,
+        size,
+        name,
+        class,
+        onblur,
+    } = props;
+    let id = use_unique_id("radio");
+    let name = name.unwrap_or_else(|| id.clone());
+    let described_by = described_by(&id, &helper_text, &error);
+    let invalid = error.is_some();
+    let field_class = field_class(size, disabled, invalid, class);
+
+    let radios = options.into_iter().enumerate().map(|(index, option)| {
+        let is_checked = *value.read() == option.value;
+        let option_value = option.value.clone();
+
+        rsx! {
+            label { key: "{index}", class: "freyr-form-check",
+                input {
+                    class: "freyr-form-check-input",
+                    r#type: "radio",
+                    name: "{name}",
+                    value: "{index}",
+                    checked: is_checked,
+                    disabled: disabled || option.disabled,
+                    onclick: move |event| {
+                        if readonly {
+                            event.prevent_default();
+                        }
+                    },
+                    onchange: move |_| {
+                        if !readonly {
+                            value.set(option_value.clone());
+                        }
+                    },
+                    onblur: move |event| {
+                        if let Some(onblur) = onblur {
+                            onblur.call(event);
+                        }
+                    },
+                }
+                span { "{option.label}" }
+            }
+        }
+    });
+
+    rsx! {
+        StyleOnce { css: INPUT_STYLES }
+
+        fieldset {
+            class: "{field_class}",
+            aria_describedby: described_by,
+            aria_invalid: "{invalid}",
+            aria_readonly: readonly.then_some("true"),
+            if let Some(label) = label {
+                legend { class: "freyr-form-field-label", "{label}" }
+            }
+            div { class: if inline { "freyr-form-radio-options inline" } else { "freyr-form-radio-options" },
+                {radios}
+            }
+            FieldMessages { id: id.clone(), helper_text, error }
+        }
+    }
+}
+
+/// Select bound to a `Signal<T>`, set to the value of the chosen option.
+///
+/// ```rust,ignore
+/// let country = use_signal(|| "fr".to_string());
+///
+/// rsx! {
+///     Select {
+///         value: country,
+///         label: "Country",
+///         options: vec![
+///             SelectOption::new("France", "fr".to_string()),
+///             SelectOption::new("Germany", "de".to_string()),
+///         ],
+///     }
+/// }
+/// ```
+#[component]
+pub fn Select<T: Clone + PartialEq + 'static>(props: SelectProps<T>) -> Element {
+    let SelectProps {
+        mut value,
+        options,
+        label,
+        helper_text,
+        error,
+        disabled,
+        readonly,
+        required,
+        size,
+        id,
+        class,
+        onblur,
+    } = props;
+    let generated_id = use_unique_id("input");
+    let id = id.unwrap_or(generated_id);
+    let described_by = described_by(&id, &helper_text, &error);
+    let invalid = error.is_some();
+    let field_class = field_class(size, disabled, invalid, class);
+    let choices = options.clone();
+
+    rsx! {
+        StyleOnce { css: INPUT_STYLES }
+
+        div { class: "{field_class}",
+            FieldLabel { id: id.clone(), label, required }
+            select {
+                id: "{id}",
+                class: "freyr-form-field-control",
+                // A select cannot be read-only, so it is disabled instead
+                disabled: disabled || readonly,
+                required,
+                aria_invalid: "{invalid}",
+                aria_describedby: described_by,
+                onchange: move |event| {
+                    let chosen = event.value().parse::<usize>().ok().and_then(|index| choices.get(index));
+                    if let Some(option) = chosen {
+                        value.set(option.value.clone());
+                    }
+                },
+                onblur: move |event| {
+                    if let Some(onblur) = onblur {
+                        onblur.call(event);
+                    }
+                },
+                for (index , option) in options.iter().enumerate() {
+                    option {
+                        key: "{index}",
+                        value: "{index}",
+                        selected: *value.read() == option.value,
+                        disabled: option.disabled,
+                        "{option.label}"
+                    }
+                }
+            }
+            FieldMessages { id: id.clone(), helper_text, error }
+        }
+    }
+}
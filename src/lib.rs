@@ -22,15 +22,23 @@
 //! - [x] Dialog
 //! - [x] Spinner
 //! - [x] Card
+//! - [x] Data table
+//! - [x] Form inputs (`TextInput`, `TextArea`, `Checkbox`, `Radio`, `Switch`, `Select`)
+//! - [x] Form state and validation (`use_form`, `Form`)
+//! - [x] Toasts (`ToastProvider`, `use_toast`)
+//! - [x] Tooltip and popover
+//! - [x] Theming and dark mode (`ThemeProvider`, `ColorModeToggle`)
+//! - [x] Styles injected once and namespaced with `freyr-` (`FreyrStylesheet`, `freyr_css`)
 //!
 //! All those components have more features than you think. For more information about them please chack them [here](https://docs.rs/freyr/latest/freyr/#functions).
 //! ## Key Features
 //! - Full customization of colors, sizes, and layouts using configuration structs and enums.
-//! - Easy integration into Dioxus web projects.
+//! - Easy integration into Dioxus web, desktop and fullstack projects: disable the default `web` feature
+//!   and enable the `desktop` feature for desktop apps, or the `server` feature when rendering on the server.
 //!
 //! ## Example Usage
 //!
-//! ```rust
+//! ```rust,no_run
 //! #![allow(non_snake_case)]
 //! use dioxus::prelude::*;
 //! use freyr::prelude::*;
@@ -50,10 +58,16 @@
 //!         nav_header: Some(String::from("Freyr")),
 //!         orientation: Some(Orientation::Center),
 //!         header_color: HeaderColor::Light,
-//!         nav_items: vec!["Home".to_string(), "About".to_string(), "Contact".to_string()],
-//!         nav_links: vec!["/".to_string(), "/about".to_string(), "/contact".to_string()],
-//!         nav_item_color: NavItemsColor::Custom("#990000"),
-//!         icon_color: IconColor::Custom("#99cc00"), // Sets the color for both the hamburger SVG and the cross SVG.
+//!         // Routes are checked at compile time, strings may be external URLs.
+//!         nav_items: vec![
+//!             NavItem::new("Home", Route::Home {}),
+//!             NavItem::new("About", "/about"),
+//!             NavItem::new("Contact", "/contact"),
+//!         ],
+//!         nav_item_color: NavItemsColor::Custom("#990000".into()),
+//!         icon_color: IconColor::Custom("#99cc00".into()), // Sets the color for both the hamburger SVG and the cross SVG.
+//!         active_color: Some(NavItemsColor::Custom("#FFD369".into())),
+//!         active_match: ActiveMatch::Prefix, // Also highlights "/about" on "/about/team".
 //!     };
 //!
 //!     rsx! {
@@ -66,8 +80,8 @@
 //! fn Home() -> Element {
 //!     rsx! {
 //!         div {
-//!             BasicButton { color: ButtonColor::Freyr, label: String::from("Go Home"), link: ButtonUrl { url: "/".to_string() } }
-//!             BasicButton { color: ButtonColor::Primary, label: String::from("Go to About"), link: ButtonUrl { url: "/about".to_string() } }
+//!             BasicButton { color: ButtonColor::Freyr, label: String::from("Go Home"), link: ButtonUrl::new(Route::Home {}) }
+//!             BasicButton { color: ButtonColor::Primary, label: String::from("Go to About"), link: ButtonUrl::new("/about") }
 //!             // Here the routing is made optional
 //!             BasicButton { color: ButtonColor::Freyr, label: String::from("Hello") }
 //!
@@ -78,9 +92,9 @@
 //! #[component]
 //! fn Dropdown() -> Element {
 //!     let dropdown_items = vec![
-//!        DropdownItem { label: String::from("Freyr"), url: String::from("/") },
-//!        DropdownItem { label: "See freyr's components".to_string(), url: "/components".to_string() },
-//!        DropdownItem { label: "Learn about dioxus".to_string(), url: "/learn-dioxus".to_string() },
+//!        DropdownItem::with_url("Freyr", Route::Home {}),
+//!        DropdownItem { label: "See freyr's components".to_string(), url: Some("/components".into()) },
+//!        DropdownItem::with_url("Learn about dioxus", "https://dioxuslabs.com/learn"),
 //!    ];
 //!
 //!    let config_dropdown = DropdownConfig {
@@ -89,7 +103,7 @@
 //!        background_color: DropdownColorScheme::Freyr,
 //!        title_color: DropdownTitleColor::Light,
 //!        labels_color: DropdownLabelsColor::Dark,
-//!        hover_color: DropdownHoverColor::Custom("#03346E"),
+//!        hover_color: DropdownHoverColor::Custom("#03346E".into()),
 //!    };
 //!     rsx! {
 //!         div {
@@ -104,31 +118,56 @@ mod accordion;
 mod assets;
 mod basic_button;
 mod carousel;
+mod data_table;
 mod dialog;
 mod dropdown;
 mod enums;
+mod floating;
+mod form;
+mod form_inputs;
 mod nav_bar;
 mod navbar_with_logo;
 pub mod prelude;
 mod scripts;
 mod spinner;
+mod styles;
 mod tabs;
 mod card;
+mod theme;
+mod time;
+mod toast;
+mod tooltip;
+mod unique_id;
 
 pub use crate::accordion::*;
 pub use crate::basic_button::*;
 pub use crate::carousel::*;
+pub use crate::data_table::*;
 pub use crate::dialog::*;
 pub use crate::dropdown::*;
+pub use crate::form::*;
+pub use crate::form_inputs::*;
 pub use crate::enums::accordion_enums::*;
 pub use crate::enums::basic_button_enums::*;
 pub use crate::enums::carousel_simple_enums::*;
+pub use crate::enums::color_enums::*;
+pub use crate::enums::data_table_enums::*;
 pub use crate::enums::dialog_enums::*;
 pub use crate::enums::dropdown_enums::*;
+pub use crate::enums::floating_enums::*;
+pub use crate::enums::form_enums::*;
+pub use crate::enums::input_enums::*;
 pub use crate::enums::navbar_enums::*;
 pub use crate::enums::tabs_enums::*;
+pub use crate::enums::theme_enums::*;
+pub use crate::enums::toast_enums::*;
 pub use crate::nav_bar::*;
 pub use crate::spinner::*;
+pub use crate::styles::*;
 pub use crate::navbar_with_logo::*;
 pub use crate::tabs::*;
-pub use crate::card::*;
\ No newline at end of file
+pub use crate::card::*;
+pub use crate::theme::*;
+pub use crate::toast::*;
+pub use crate::tooltip::*;
+pub use crate::unique_id::*;
\ No newline at end of file
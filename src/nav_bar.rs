@@ -1,64 +1,234 @@
 use crate::assets::navbar_dropdown_styles::NAVBAR_DROPDOWN_STYLES;
 use crate::assets::navbar_style::NAVBAR_STYLES;
-use crate::enums::navbar_enums::NavbarConfig;
+use crate::enums::color_enums::Color;
+use crate::enums::navbar_enums::{
+    ActiveMatch, DropdownConfigNavBar, NavItem, NavbarConfig, NavbarDropdownConfig,
+};
+use crate::styles::StyleOnce;
+use crate::theme::use_theme;
 use crate::DropdownConfig;
 use crate::{DropdownButtonConfig, DropdownMenu, DropdownMenuButton, Orientation};
 use dioxus::prelude::*;
 
+/// Returns the path of the current route, or `None` when rendered outside of a router.
+/// The calling component re-renders whenever the route changes.
+pub(crate) fn current_path() -> Option<String> {
+    try_router().map(|router| router.full_route_string())
+}
+
+/// Logs the invalid navigation items of a navbar once, instead of silently rendering them.
+pub(crate) fn use_nav_items_validation(items: &[NavItem]) {
+    let errors = items
+        .iter()
+        .filter_map(|item| item.validate().err())
+        .collect::<Vec<_>>();
+    use_hook(move || {
+        for error in errors {
+            dioxus::logger::tracing::warn!("freyr: {error}");
+        }
+    });
+}
+
+/// A navigation item of the navbars, marked as active when it matches the current route.
+/// Items with children open a nested menu, rendered with `nested` items.
+///
+/// When `open_menu` is given, the nested menu is open while it holds `menu_index`, so that opening it
+/// closes the other menus of the navbar sharing the signal.
+#[component]
+pub(crate) fn NavbarItem(
+    item: NavItem,
+    color: Color,
+    active_color: Color,
+    active_match: ActiveMatch,
+    background_color: Color,
+    #[props(default)] nested: bool,
+    #[props(default)] open_menu: Option<Signal<Option<usize>>>,
+    #[props(default)] menu_index: usize,
+    on_navigate: EventHandler<()>,
+) -> Element {
+    let local_open = use_signal(|| None::<usize>);
+    let shared = open_menu.is_some();
+    let mut open_menu = open_menu.unwrap_or(local_open);
+    let submenu_open = open_menu() == Some(menu_index);
+    let active = current_path().is_some_and(|path| item.is_active(&path, active_match));
+
+    let mut class = String::from(if nested {
+        "freyr-navbar-dropdown-item"
+    } else {
+        "freyr-menu-item"
+    });
+    if active {
+        class.push_str(" active");
+    }
+    if item.disabled {
+        class.push_str(" disabled");
+    }
+    let style = if active {
+        format!("color: {active_color};")
+    } else {
+        format!("color: {color};")
+    };
+
+    let content = rsx! {
+        if let Some(icon) = item.icon.clone() {
+            span { class: "freyr-menu-item-icon", aria_hidden: "true", {icon} }
+        }
+        span { "{item.label}" }
+        if let Some(badge) = &item.badge {
+            span { class: "freyr-menu-item-badge", "{badge}" }
+        }
+    };
+
+    if !item.children.is_empty() {
+        return rsx! {
+            div { class: "freyr-navbar-dropdown",
+                // A shared menu is closed by the overlay of the navbar owning the signal
+                if submenu_open && !shared {
+                    div {
+                        class: "freyr-nav-submenu-overlay",
+                        onclick: move |_| open_menu.set(None),
+                    }
+                }
+                button {
+                    class: "{class} freyr-navbar-dropdown-toggle",
+                    r#type: "button",
+                    style: "{style}",
+                    disabled: item.disabled,
+                    aria_haspopup: "true",
+                    aria_expanded: "{submenu_open}",
+                    onclick: move |_| {
+                        if open_menu() == Some(menu_index) {
+                            open_menu.set(None);
+                        } else {
+                            open_menu.set(Some(menu_index));
+                        }
+                    },
+                    {content}
+                    svg {
+                        class: if submenu_open { "freyr-navbar-dropdown-arrow open" } else { "freyr-navbar-dropdown-arrow" },
+                        xmlns: "http://www.w3.org/2000/svg",
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
+                        path { d: "M6 9l6 6 6-6" }
+                    }
+                }
+                if submenu_open {
+                    div {
+                        class: "freyr-navbar-dropdown-content",
+                        style: "background-color: {background_color};",
+                        for child in item.children.iter().cloned() {
+                            NavbarItem {
+                                item: child,
+                                color: color.clone(),
+                                active_color: active_color.clone(),
+                                active_match,
+                                background_color: background_color.clone(),
+                                nested: true,
+                                on_navigate: move |_| {
+                                    open_menu.set(None);
+                                    on_navigate.call(());
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    match item.target.clone() {
+        Some(target) if !item.disabled => rsx! {
+            Link {
+                class: "{class}",
+                to: target,
+                new_tab: item.new_tab,
+                rel: item.new_tab.then(|| "noopener noreferrer".to_string()),
+                aria_current: if active { "page" } else { "false" },
+                style: "{style}",
+                onclick: move |_| on_navigate.call(()),
+                {content}
+            }
+        },
+        _ => rsx! {
+            span { class: "{class}", aria_disabled: "true", style: "{style}", {content} }
+        },
+    }
+}
+
 /// You can configure background color, navigation items, and icon colors.
+/// Children are rendered after the navigation items, e.g. a `ColorModeToggle`.
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 /// let navbar_config = NavbarConfig {
 ///     background_color: ColorScheme::Freyr,
 ///     nav_header: Some(String::from("Freyr")),
 ///     orientation: Some(Orientation::Center),
 ///     header_color: HeaderColor::Light,
-///     nav_items: vec!["Home".to_string(), "About".to_string(), "Contact".to_string()],
-///     nav_links: vec!["/".to_string(), "/about".to_string(), "/contact".to_string()],
+///     nav_items: vec![
+///         NavItem::new("Home", "/"),
+///         NavItem::new("About", "/about").badge("New"),
+///         NavItem::menu(
+///             "Resources",
+///             vec![
+///                 NavItem::new("Blog", "/blog"),
+///                 NavItem::new("Docs", "https://docs.rs/freyr").new_tab(),
+///             ],
+///         ),
+///     ],
 ///     nav_item_color: NavItemsColor::Light,
 ///     icon_color: IconColor::White,
+///     active_color: Some(NavItemsColor::Custom("#FFD369".into())),
+///     active_match: ActiveMatch::Prefix, // Also highlights "/about" on "/about/team".
 /// };
 ///
 /// rsx! {
-///     Navbar { navbar_config }
+///     Navbar { navbar_config,
+///         ColorModeToggle {}
+///     }
 /// };
 /// ```
 #[component]
-pub fn Navbar(navbar_config: NavbarConfig) -> Element {
+pub fn Navbar(navbar_config: NavbarConfig, children: Element) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
+    use_nav_items_validation(&navbar_config.nav_items);
     let orientation_class = match navbar_config
         .orientation
         .clone()
         .unwrap_or(Orientation::Right)
     {
-        Orientation::Left => "menu-items left",
-        Orientation::Center => "menu-items center",
-        Orientation::Right => "menu-items right",
+        Orientation::Left => "freyr-menu-items left",
+        Orientation::Center => "freyr-menu-items center",
+        Orientation::Right => "freyr-menu-items right",
     };
 
     rsx! {
         div {
-            style { "{NAVBAR_STYLES}" }
+            StyleOnce { css: NAVBAR_STYLES }
 
             nav {
-                class: "navbar",
-                style: "background-color: {navbar_config.background_color.as_css_class()};",
+                class: "freyr-navbar",
+                style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
 
-                div { class: "nav-div",
+                div { class: "freyr-nav-div",
 
                     div { class: "",
                         match &navbar_config.nav_header {
                             Some(header) => rsx! {
                                 div {
-                                    class: "nav-header-wrapper",
-                                    style: "color: {navbar_config.header_color.as_css_class()};",
+                                    class: "freyr-nav-header-wrapper",
+                                    style: "color: {navbar_config.header_color.to_css_color(&theme)};",
                                     Link { to: "/", "{header}" }
                                 }
                             },
                             None => rsx! {
-                                div { class: "no-nav-header",
+                                div { class: "freyr-no-nav-header",
                                     span { "" }
                                 }
                             },
@@ -66,7 +236,7 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
                     }
 
                     button {
-                        class: "hamburger",
+                        class: "freyr-hamburger",
                         onclick: move |_| menu_open.set(!menu_open()),
 
                         match menu_open() {
@@ -78,7 +248,7 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -94,7 +264,7 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -108,21 +278,31 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
 
                 div {
                     class: match menu_open() {
-                        true => "menu open",
-                        false => "menu",
+                        true => "freyr-menu open",
+                        false => "freyr-menu",
                     },
-                    style: "background-color: {navbar_config.background_color.as_css_class()};",
+                    style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
 
                     div { class: "{orientation_class}",
-                        for (item , link) in navbar_config.nav_items.iter().zip(navbar_config.nav_links.iter()) {
-                            Link {
-                                class: "menu-item",
-                                to: "{link}",
-                                style: "color: {navbar_config.nav_item_color.as_css_class()};",
-                                onclick: move |_| menu_open.set(false),
-                                "{item}"
+                        for item in navbar_config.nav_items.iter().cloned() {
+                            NavbarItem {
+                                item,
+                                color: navbar_config.nav_item_color.to_css_color(&theme),
+                                active_color: navbar_config
+                                    .active_color
+                                    .as_ref()
+                                    .unwrap_or(&navbar_config.nav_item_color)
+                                    .to_css_color(&theme),
+                                active_match: navbar_config.active_match,
+                                background_color: navbar_config.background_color.to_css_color(&theme),
+                                on_navigate: move |_| menu_open.set(false),
                             }
                         }
+                        div {
+                            class: "freyr-nav-extra",
+                            style: "color: {navbar_config.nav_item_color.to_css_color(&theme)};",
+                            {children}
+                        }
                     }
                 }
             }
@@ -131,7 +311,7 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
 }
 
 /// Navbar with a Dropdown menu implementation example:
-/// ```rust
+/// ```rust,ignore
 /// #[component]
 /// pub fn Navigation() -> Element {
 ///     let navbar_config = NavbarConfig {
@@ -140,27 +320,24 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
 ///         orientation: Some(Orientation::Center),
 ///         header_color: HeaderColor::Light,
 ///         nav_items: vec![
-///             "Home".to_string(),
-///             "About".to_string(),
-///             "Contact".to_string(),
-///         ],
-///         nav_links: vec![
-///             "/".to_string(),
-///             "/about".to_string(),
-///             "/contact".to_string(),
+///             NavItem::new("Home", "/"),
+///             NavItem::new("About", "/about"),
+///             NavItem::new("Contact", "/contact"),
 ///         ],
 ///         nav_item_color: NavItemsColor::Light,
 ///         icon_color: IconColor::White,
+///         active_color: None,
+///         active_match: ActiveMatch::Exact,
 ///     };
 ///
 ///     let dropdown_items = vec![
 ///         DropdownItem {
 ///             label: "Home".to_string(),
-///             url: Some("/".to_string()),
+///             url: Some("/".into()),
 ///         },
 ///         DropdownItem {
 ///             label: "About".to_string(),
-///             url: Some("/about".to_string()),
+///             url: Some("/about".into()),
 ///         },
 ///         DropdownItem::without_url("A Label without route"),
 ///         DropdownItem {
@@ -175,7 +352,7 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
 ///         background_color: DropdownColorScheme::Dark,
 ///         title_color: DropdownTitleColor::Light,
 ///         labels_color: DropdownLabelsColor::Light,
-///         hover_color: DropdownHoverColor::Custom("#47453e"),
+///         hover_color: DropdownHoverColor::Custom("#47453e".into()),
 ///     };
 ///
 ///     rsx! {
@@ -186,46 +363,51 @@ pub fn Navbar(navbar_config: NavbarConfig) -> Element {
 /// ```
 
 #[component]
-pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConfig) -> Element {
+pub fn NavbarDropdown(
+    navbar_config: NavbarConfig,
+    config_dropdown: DropdownConfig,
+    children: Element,
+) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
-    let _dropdown_open = use_signal(|| None::<usize>);
+    use_nav_items_validation(&navbar_config.nav_items);
     let orientation_class = match navbar_config
         .orientation
         .clone()
         .unwrap_or(Orientation::Right)
     {
-        Orientation::Left => "menu-items left",
-        Orientation::Center => "menu-items center",
-        Orientation::Right => "menu-items right",
+        Orientation::Left => "freyr-menu-items left",
+        Orientation::Center => "freyr-menu-items center",
+        Orientation::Right => "freyr-menu-items right",
     };
 
     let has_header = navbar_config.nav_header.is_some();
     let navbar_class = if has_header {
-        "navbar"
+        "freyr-navbar"
     } else {
-        "navbar no-header"
+        "freyr-navbar freyr-no-header"
     };
 
     rsx! {
         div {
-            style { "{NAVBAR_DROPDOWN_STYLES}" }
+            StyleOnce { css: NAVBAR_DROPDOWN_STYLES }
 
             nav {
                 class: "{navbar_class}",
-                style: "background-color: {navbar_config.background_color.as_css_class()};",
-                div { class: "nav-div",
+                style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
+                div { class: "freyr-nav-div",
 
                     div { class: "",
                         match &navbar_config.nav_header {
                             Some(header) => rsx! {
                                 div {
-                                    class: "nav-header-wrapper",
-                                    style: "color: {navbar_config.header_color.as_css_class()};",
+                                    class: "freyr-nav-header-wrapper",
+                                    style: "color: {navbar_config.header_color.to_css_color(&theme)};",
                                     Link { to: "/", "{header}" }
                                 }
                             },
                             None => rsx! {
-                                div { class: "no-nav-header",
+                                div { class: "freyr-no-nav-header",
                                     span { "" }
                                 }
                             },
@@ -233,7 +415,7 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
                     }
 
                     button {
-                        class: "hamburger",
+                        class: "freyr-hamburger",
                         onclick: move |_| menu_open.set(!menu_open()),
 
                         match menu_open() {
@@ -245,7 +427,7 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -261,7 +443,7 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -275,23 +457,36 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
 
                 div {
                     class: match menu_open() {
-                        true => "menu open",
-                        false => "menu",
+                        true => "freyr-menu open",
+                        false => "freyr-menu",
                     },
-                    style: "background-color: {navbar_config.background_color.as_css_class()};",
+                    style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
 
                     div { class: "{orientation_class}",
-                        for (item , link) in navbar_config.nav_items.iter().zip(navbar_config.nav_links.iter()) {
-                            Link {
-                                class: "menu-item",
-                                to: "{link}",
-                                style: "color: {navbar_config.nav_item_color.as_css_class()};",
-                                onclick: move |_| menu_open.set(false),
-                                "{item}"
+                        for item in navbar_config.nav_items.iter().cloned() {
+                            NavbarItem {
+                                item,
+                                color: navbar_config.nav_item_color.to_css_color(&theme),
+                                active_color: navbar_config
+                                    .active_color
+                                    .as_ref()
+                                    .unwrap_or(&navbar_config.nav_item_color)
+                                    .to_css_color(&theme),
+                                active_match: navbar_config.active_match,
+                                background_color: navbar_config.background_color.to_css_color(&theme),
+                                on_navigate: move |_| menu_open.set(false),
+                            }
+                        }
+                        div { class: "freyr-dropdown-navbar",
+                            DropdownMenu {
+                                config_dropdown: config_dropdown.clone(),
+                                active_match: navbar_config.active_match,
                             }
                         }
-                        div { class: "dropdown-navbar",
-                            DropdownMenu { config_dropdown: config_dropdown.clone() }
+                        div {
+                            class: "freyr-nav-extra",
+                            style: "color: {navbar_config.nav_item_color.to_css_color(&theme)};",
+                            {children}
                         }
                     }
                 }
@@ -302,7 +497,7 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
 
 /// Example use of the navbar with a **dropdown menu** that allows you to pass onclick events.
 /// This example introduces the use of _dioxus-i18n_ for internationalization.
-/// ```rust
+/// ```rust,ignore
 /// #[component]
 /// pub fn Navigation() -> Element {
 ///     let mut i18n = i18n();
@@ -324,7 +519,7 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
 ///         background_color: DropdownColorScheme::Dark,
 ///         title_color: DropdownTitleColor::Light,
 ///         labels_color: DropdownLabelsColor::Light,
-///         hover_color: DropdownHoverColor::Custom("#03346E"),
+///         hover_color: DropdownHoverColor::Custom("#03346E".into()),
 ///     };
 ///
 ///     let navbar_config = NavbarConfig {
@@ -333,17 +528,14 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
 ///         orientation: Some(Orientation::Center),
 ///         header_color: HeaderColor::Light,
 ///         nav_items: vec![
-///             "Home".to_string(),
-///             t!("about"),
-///             "Contact".to_string(),
-///         ],
-///         nav_links: vec![
-///             "/".to_string(),
-///             "/about".to_string(),
-///             "/contact".to_string(),
+///             NavItem::new("Home", "/"),
+///             NavItem::new(t!("about"), "/about"),
+///             NavItem::new("Contact", "/contact"),
 ///         ],
 ///         nav_item_color: NavItemsColor::Light,
 ///         icon_color: IconColor::White,
+///         active_color: None,
+///         active_match: ActiveMatch::Exact,
 ///     };
 ///
 ///
@@ -357,46 +549,48 @@ pub fn NavbarDropdown(navbar_config: NavbarConfig, config_dropdown: DropdownConf
 pub fn NavbarDropdownButtons(
     navbar_config: NavbarConfig,
     config_dropdown: DropdownButtonConfig,
+    children: Element,
 ) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
-    let _dropdown_open = use_signal(|| None::<usize>);
+    use_nav_items_validation(&navbar_config.nav_items);
     let orientation_class = match navbar_config
         .orientation
         .clone()
         .unwrap_or(Orientation::Right)
     {
-        Orientation::Left => "menu-items left",
-        Orientation::Center => "menu-items center",
-        Orientation::Right => "menu-items right",
+        Orientation::Left => "freyr-menu-items left",
+        Orientation::Center => "freyr-menu-items center",
+        Orientation::Right => "freyr-menu-items right",
     };
 
     rsx! {
         div {
-            style { "{NAVBAR_DROPDOWN_STYLES}" }
+            StyleOnce { css: NAVBAR_DROPDOWN_STYLES }
 
             nav {
-                class: "navbar",
-                style: "background-color: {navbar_config.background_color.as_css_class()};",
+                class: "freyr-navbar",
+                style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
 
-                div { class: "nav-div",
+                div { class: "freyr-nav-div",
                     div { class: "",
                         match &navbar_config.nav_header {
                             Some(header) => rsx! {
                                 div {
-                                    class: "nav-header-wrapper",
-                                    style: "color: {navbar_config.header_color.as_css_class()};",
+                                    class: "freyr-nav-header-wrapper",
+                                    style: "color: {navbar_config.header_color.to_css_color(&theme)};",
                                     Link { to: "/", "{header}" }
                                 }
                             },
                             None => rsx! {
-                                div { class: "no-nav-header",
+                                div { class: "freyr-no-nav-header",
                                     span { "" }
                                 }
                             },
                         }
                     }
                     button {
-                        class: "hamburger",
+                        class: "freyr-hamburger",
                         onclick: move |_| menu_open.set(!menu_open()),
 
                         match menu_open() {
@@ -408,7 +602,7 @@ pub fn NavbarDropdownButtons(
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -424,7 +618,7 @@ pub fn NavbarDropdownButtons(
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -438,24 +632,355 @@ pub fn NavbarDropdownButtons(
 
                 div {
                     class: match menu_open() {
-                        true => "menu open",
-                        false => "menu",
+                        true => "freyr-menu open",
+                        false => "freyr-menu",
                     },
-                    style: "background-color: {navbar_config.background_color.as_css_class()};",
+                    style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
 
                     div { class: "{orientation_class}",
-                        for (item , link) in navbar_config.nav_items.iter().zip(navbar_config.nav_links.iter()) {
-                            Link {
-                                class: "menu-item",
-                                to: "{link}",
-                                style: "color: {navbar_config.nav_item_color.as_css_class()};",
-                                onclick: move |_| menu_open.set(false),
-                                "{item}"
+                        for item in navbar_config.nav_items.iter().cloned() {
+                            NavbarItem {
+                                item,
+                                color: navbar_config.nav_item_color.to_css_color(&theme),
+                                active_color: navbar_config
+                                    .active_color
+                                    .as_ref()
+                                    .unwrap_or(&navbar_config.nav_item_color)
+                                    .to_css_color(&theme),
+                                active_match: navbar_config.active_match,
+                                background_color: navbar_config.background_color.to_css_color(&theme),
+                                on_navigate: move |_| menu_open.set(false),
                             }
                         }
-                        div { class: "dropdown-navbar",
+                        div { class: "freyr-dropdown-navbar",
                             DropdownMenuButton { config_dropdown: config_dropdown.clone() }
                         }
+                        div {
+                            class: "freyr-nav-extra",
+                            style: "color: {navbar_config.nav_item_color.to_css_color(&theme)};",
+                            {children}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Navbar rendering any number of dropdowns among its navigation items, only one of them being open at a time.
+/// Each dropdown is placed before the navigation item at its `position`, or after every item without one.
+/// ```rust,ignore
+/// #[component]
+/// pub fn Navigation() -> Element {
+///     let products = DropdownConfigNavBar::new(
+///         "Products".to_string(),
+///         vec![
+///             ("Buttons".to_string(), "/buttons".into()),
+///             ("Navbars".to_string(), "/navbars".into()),
+///         ],
+///         Some("Our components".to_string()),
+///         None,
+///         None,
+///         None,
+///     )
+///     .at_position(1);
+///
+///     let languages = DropdownConfigNavBar::new(
+///         "Languages".to_string(),
+///         vec![
+///             ("English".to_string(), "/en".into()),
+///             ("Français".to_string(), "/fr".into()),
+///         ],
+///         None,
+///         Some(ColorScheme::Dark),
+///         None,
+///         Some(NavItemsColor::Light),
+///     );
+///
+///     let navbar_config = NavbarDropdownConfig {
+///         background_color: ColorScheme::Freyr,
+///         nav_header: Some(String::from("Freyr")),
+///         header_color: HeaderColor::Light,
+///         nav_items: vec![NavItem::new("Home", "/"), NavItem::new("About", "/about")],
+///         nav_item_color: NavItemsColor::Light,
+///         icon_color: IconColor::White,
+///         active_color: None,
+///         active_match: ActiveMatch::Exact,
+///         // Renders: Home, Products, About, Languages
+///         dropdowns: vec![products, languages],
+///         orientation: Some(Orientation::Center),
+///     };
+///
+///     rsx! {
+///         NavbarWithDropdowns { navbar_config }
+///         Outlet::<Route> {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn NavbarWithDropdowns(navbar_config: NavbarDropdownConfig, children: Element) -> Element {
+    let theme = use_theme();
+    let mut menu_open = use_signal(|| false);
+    use_nav_items_validation(&navbar_config.nav_items);
+    let mut dropdown_open = use_signal(|| None::<usize>);
+    let orientation_class = match navbar_config
+        .orientation
+        .clone()
+        .unwrap_or(Orientation::Right)
+    {
+        Orientation::Left => "freyr-menu-items left",
+        Orientation::Center => "freyr-menu-items center",
+        Orientation::Right => "freyr-menu-items right",
+    };
+
+    let navbar_class = if navbar_config.nav_header.is_some() {
+        "freyr-navbar"
+    } else {
+        "freyr-navbar freyr-no-header"
+    };
+
+    let items = &navbar_config.nav_items;
+
+    // Every slot holds the dropdowns placed before a navigation item, the last one those placed after all items
+    let slots = (0..=items.len())
+        .map(|slot| {
+            let dropdowns = navbar_config
+                .dropdowns
+                .iter()
+                .enumerate()
+                .filter(|(_, dropdown)| {
+                    dropdown.position.unwrap_or(items.len()).min(items.len()) == slot
+                })
+                .map(|(index, dropdown)| (index, dropdown.clone()))
+                .collect::<Vec<_>>();
+            // Nested menus of the items share `dropdown_open`, numbered after the dropdowns
+            let menu_index = navbar_config.dropdowns.len() + slot;
+            (
+                dropdowns,
+                items.get(slot).cloned().map(|item| (item, menu_index)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div {
+            StyleOnce { css: NAVBAR_DROPDOWN_STYLES }
+
+            if dropdown_open().is_some() {
+                div {
+                    class: "freyr-navbar-dropdown-overlay",
+                    onclick: move |_| dropdown_open.set(None),
+                }
+            }
+
+            nav {
+                class: "{navbar_class}",
+                style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
+                div { class: "freyr-nav-div",
+
+                    div { class: "",
+                        match &navbar_config.nav_header {
+                            Some(header) => rsx! {
+                                div {
+                                    class: "freyr-nav-header-wrapper",
+                                    style: "color: {navbar_config.header_color.to_css_color(&theme)};",
+                                    Link { to: "/", "{header}" }
+                                }
+                            },
+                            None => rsx! {
+                                div { class: "freyr-no-nav-header",
+                                    span { "" }
+                                }
+                            },
+                        }
+                    }
+
+                    button {
+                        class: "freyr-hamburger",
+                        onclick: move |_| menu_open.set(!menu_open()),
+
+                        match menu_open() {
+                            true => {
+                                rsx! {
+                                    svg {
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        width: "32",
+                                        height: "32",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
+                                        stroke_width: "2",
+                                        stroke_linecap: "round",
+                                        stroke_linejoin: "round",
+                                        path { d: "M18 6L6 18M6 6L18 18" }
+                                    }
+                                }
+                            }
+                            false => {
+                                rsx! {
+                                    svg {
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        width: "32",
+                                        height: "32",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "{navbar_config.icon_color.to_css_color(&theme)}",
+                                        stroke_width: "2",
+                                        stroke_linecap: "round",
+                                        stroke_linejoin: "round",
+                                        path { d: "M4 6h16M4 12h16M4 18h16" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: match menu_open() {
+                        true => "freyr-menu open",
+                        false => "freyr-menu",
+                    },
+                    style: "background-color: {navbar_config.background_color.to_css_color(&theme)};",
+
+                    div { class: "{orientation_class}",
+                        for (dropdowns , item) in slots {
+                            for (index , dropdown) in dropdowns {
+                                NavbarInlineDropdown {
+                                    key: "{index}",
+                                    background_color: dropdown
+                                        .background_color
+                                        .clone()
+                                        .unwrap_or(navbar_config.background_color.clone())
+                                        .to_css_color(&theme),
+                                    title_color: dropdown
+                                        .title_color
+                                        .clone()
+                                        .unwrap_or(navbar_config.nav_item_color.clone())
+                                        .to_css_color(&theme),
+                                    item_color: dropdown
+                                        .item_color
+                                        .clone()
+                                        .unwrap_or(navbar_config.nav_item_color.clone())
+                                        .to_css_color(&theme),
+                                    active_color: navbar_config
+                                        .active_color
+                                        .clone()
+                                        .map(|color| color.to_css_color(&theme)),
+                                    active_match: navbar_config.active_match,
+                                    dropdown,
+                                    index,
+                                    dropdown_open,
+                                    menu_open,
+                                }
+                            }
+                            if let Some((item , menu_index)) = item {
+                                NavbarItem {
+                                    item,
+                                    color: navbar_config.nav_item_color.to_css_color(&theme),
+                                    active_color: navbar_config
+                                        .active_color
+                                        .as_ref()
+                                        .unwrap_or(&navbar_config.nav_item_color)
+                                        .to_css_color(&theme),
+                                    active_match: navbar_config.active_match,
+                                    background_color: navbar_config.background_color.to_css_color(&theme),
+                                    open_menu: dropdown_open,
+                                    menu_index,
+                                    on_navigate: move |_| {
+                                        dropdown_open.set(None);
+                                        menu_open.set(false);
+                                    },
+                                }
+                            }
+                        }
+                        div {
+                            class: "freyr-nav-extra",
+                            style: "color: {navbar_config.nav_item_color.to_css_color(&theme)};",
+                            {children}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A single dropdown of the **`NavbarWithDropdowns`** component, open when `dropdown_open` holds its `index`.
+/// The item matching the current route, and the dropdown containing it, are marked as active.
+#[component]
+fn NavbarInlineDropdown(
+    dropdown: DropdownConfigNavBar,
+    index: usize,
+    dropdown_open: Signal<Option<usize>>,
+    menu_open: Signal<bool>,
+    background_color: Color,
+    title_color: Color,
+    item_color: Color,
+    active_color: Option<Color>,
+    active_match: ActiveMatch,
+) -> Element {
+    let is_open = dropdown_open() == Some(index);
+    let current = current_path();
+    let is_active = |target: &NavigationTarget| {
+        current
+            .as_ref()
+            .is_some_and(|path| active_match.is_active(path, target))
+    };
+    let has_active = dropdown.items.iter().any(|(_, target)| is_active(target));
+    let active_color = active_color.unwrap_or_else(|| item_color.clone());
+
+    rsx! {
+        div { class: "freyr-navbar-dropdown",
+            button {
+                class: if has_active { "freyr-menu-item freyr-navbar-dropdown-toggle active" } else { "freyr-menu-item freyr-navbar-dropdown-toggle" },
+                style: "color: {title_color};",
+                aria_haspopup: "true",
+                aria_expanded: "{is_open}",
+                onclick: move |_| {
+                    if dropdown_open() == Some(index) {
+                        dropdown_open.set(None);
+                    } else {
+                        dropdown_open.set(Some(index));
+                    }
+                },
+                "{dropdown.label}"
+                svg {
+                    class: if is_open { "freyr-navbar-dropdown-arrow open" } else { "freyr-navbar-dropdown-arrow" },
+                    xmlns: "http://www.w3.org/2000/svg",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    path { d: "M6 9l6 6 6-6" }
+                }
+            }
+
+            if is_open {
+                div {
+                    class: "freyr-navbar-dropdown-content",
+                    style: "background-color: {background_color};",
+                    if let Some(title) = &dropdown.title {
+                        span {
+                            class: "freyr-navbar-dropdown-title",
+                            style: "color: {title_color};",
+                            "{title}"
+                        }
+                    }
+                    for (label , target) in dropdown.items.iter() {
+                        Link {
+                            class: if is_active(target) { "freyr-navbar-dropdown-item active" } else { "freyr-navbar-dropdown-item" },
+                            to: target.clone(),
+                            aria_current: if is_active(target) { "page" } else { "false" },
+                            style: if is_active(target) { format!("color: {active_color};") } else { format!("color: {item_color};") },
+                            onclick: move |_| {
+                                dropdown_open.set(None);
+                                menu_open.set(false);
+                            },
+                            "{label}"
+                        }
                     }
                 }
             }
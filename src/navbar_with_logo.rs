@@ -1,5 +1,8 @@
 use crate::assets::navbar_style::NAVBAR_STYLES;
 use crate::enums::navbar_enums::NavbarWithLogoConfig;
+use crate::nav_bar::{use_nav_items_validation, NavbarItem};
+use crate::styles::StyleOnce;
+use crate::theme::use_theme;
 use crate::Orientation;
 use dioxus::prelude::*;
 
@@ -7,19 +10,24 @@ use dioxus::prelude::*;
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 ///    #[component]
 ///    fn Navigation() -> Element {
 ///     const LOGO: Asset = asset!("./assets/logo.png");
 ///
 ///     let navbar_logo_config = NavbarWithLogoConfig {
 ///         background_color: ColorScheme::Freyr,
-///         nav_items: vec!["Home".to_string(), "About".to_string(), "Contact".to_string()],
-///         nav_links: vec!["/".to_string(), "/about".to_string(), "/contact".to_string()],
+///         nav_items: vec![
+///             NavItem::new("Home", "/"),
+///             NavItem::new("About", "/about"),
+///             NavItem::new("Contact", "/contact"),
+///         ],
 ///         orientation: Some(Orientation::Center),
 ///         nav_item_color: NavItemsColor::Light,
 ///         icon_color: IconColor::White,
-///         logo_url: String::from("/"),
+///         active_color: None,
+///         active_match: ActiveMatch::Exact,
+///         logo_url: Route::Home {}.into(),
 ///         logo_src: LOGO,
 ///         logo_alt: String::from("logo"),
 ///     };
@@ -31,38 +39,40 @@ use dioxus::prelude::*;
 ///    }
 /// ```
 #[component]
-pub fn NavbarWithLogo(navbar_logo_config: NavbarWithLogoConfig) -> Element {
+pub fn NavbarWithLogo(navbar_logo_config: NavbarWithLogoConfig, children: Element) -> Element {
+    let theme = use_theme();
     let mut menu_open = use_signal(|| false);
+    use_nav_items_validation(&navbar_logo_config.nav_items);
     let orientation_class = match navbar_logo_config
         .orientation
         .clone()
         .unwrap_or(Orientation::Right)
     {
-        Orientation::Left => "menu-items left",
-        Orientation::Center => "menu-items center",
-        Orientation::Right => "menu-items right",
+        Orientation::Left => "freyr-menu-items left",
+        Orientation::Center => "freyr-menu-items center",
+        Orientation::Right => "freyr-menu-items right",
     };
 
     rsx! {
         div {
-            style { "{NAVBAR_STYLES}" }
+            StyleOnce { css: NAVBAR_STYLES }
 
             nav {
-                class: "navbar",
-                style: "background-color: {navbar_logo_config.background_color.as_css_class()};",
+                class: "freyr-navbar",
+                style: "background-color: {navbar_logo_config.background_color.to_css_color(&theme)};",
 
-                div { class: "nav-div",
+                div { class: "freyr-nav-div",
 
-                    Link { to: "{navbar_logo_config.logo_url}",
+                    Link { to: navbar_logo_config.logo_url.clone(),
                         img {
-                            class: "nav-logo",
+                            class: "freyr-nav-logo",
                             src: "{navbar_logo_config.logo_src}",
                             alt: "{navbar_logo_config.logo_alt}",
                         }
                     }
 
                     button {
-                        class: "hamburger",
+                        class: "freyr-hamburger",
                         onclick: move |_| menu_open.set(!menu_open()),
 
                         match menu_open() {
@@ -74,7 +84,7 @@ pub fn NavbarWithLogo(navbar_logo_config: NavbarWithLogoConfig) -> Element {
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_logo_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_logo_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -90,7 +100,7 @@ pub fn NavbarWithLogo(navbar_logo_config: NavbarWithLogoConfig) -> Element {
                                         height: "32",
                                         view_box: "0 0 24 24",
                                         fill: "none",
-                                        stroke: "{navbar_logo_config.icon_color.as_css_class()}",
+                                        stroke: "{navbar_logo_config.icon_color.to_css_color(&theme)}",
                                         stroke_width: "2",
                                         stroke_linecap: "round",
                                         stroke_linejoin: "round",
@@ -104,21 +114,31 @@ pub fn NavbarWithLogo(navbar_logo_config: NavbarWithLogoConfig) -> Element {
 
                 div {
                     class: match menu_open() {
-                        true => "menu open",
-                        false => "menu",
+                        true => "freyr-menu open",
+                        false => "freyr-menu",
                     },
-                    style: "background-color: {navbar_logo_config.background_color.as_css_class()};",
+                    style: "background-color: {navbar_logo_config.background_color.to_css_color(&theme)};",
 
                     div { class: "{orientation_class}",
-                        for (item , link) in navbar_logo_config.nav_items.iter().zip(navbar_logo_config.nav_links.iter()) {
-                            Link {
-                                class: "menu-item",
-                                to: "{link}",
-                                style: "color: {navbar_logo_config.nav_item_color.as_css_class()};",
-                                onclick: move |_| menu_open.set(false),
-                                "{item}"
+                        for item in navbar_logo_config.nav_items.iter().cloned() {
+                            NavbarItem {
+                                item,
+                                color: navbar_logo_config.nav_item_color.to_css_color(&theme),
+                                active_color: navbar_logo_config
+                                    .active_color
+                                    .as_ref()
+                                    .unwrap_or(&navbar_logo_config.nav_item_color)
+                                    .to_css_color(&theme),
+                                active_match: navbar_logo_config.active_match,
+                                background_color: navbar_logo_config.background_color.to_css_color(&theme),
+                                on_navigate: move |_| menu_open.set(false),
                             }
                         }
+                        div {
+                            class: "freyr-nav-extra",
+                            style: "color: {navbar_logo_config.nav_item_color.to_css_color(&theme)};",
+                            {children}
+                        }
                     }
                 }
             }
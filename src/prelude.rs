@@ -2,16 +2,31 @@ pub use crate::accordion::*;
 pub use crate::basic_button::*;
 pub use crate::dialog::*;
 pub use crate::carousel::*;
+pub use crate::data_table::*;
 pub use crate::dropdown::*;
+pub use crate::form::*;
+pub use crate::form_inputs::*;
 pub use crate::enums::accordion_enums::*;
 pub use crate::enums::dialog_enums::*;
 pub use crate::enums::basic_button_enums::*;
 pub use crate::enums::carousel_simple_enums::*;
+pub use crate::enums::color_enums::*;
+pub use crate::enums::data_table_enums::*;
 pub use crate::enums::dropdown_enums::*;
+pub use crate::enums::floating_enums::*;
+pub use crate::enums::form_enums::*;
+pub use crate::enums::input_enums::*;
 pub use crate::enums::navbar_enums::*;
 pub use crate::enums::tabs_enums::*;
+pub use crate::enums::theme_enums::*;
+pub use crate::enums::toast_enums::*;
 pub use crate::nav_bar::*;
 pub use crate::navbar_with_logo::*;
 pub use crate::tabs::*;
 pub use crate::spinner::*;
-pub use crate::card::*;
\ No newline at end of file
+pub use crate::styles::*;
+pub use crate::card::*;
+pub use crate::theme::*;
+pub use crate::toast::*;
+pub use crate::tooltip::*;
+pub use crate::unique_id::*;
\ No newline at end of file
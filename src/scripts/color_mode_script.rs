@@ -0,0 +1,25 @@
+pub const COLOR_MODE_SCRIPT: &str = r#"
+    const storageKey = {storage_key};
+    const media = window.matchMedia('(prefers-color-scheme: dark)');
+
+    let stored = null;
+    try {
+        stored = window.localStorage.getItem(storageKey);
+    } catch (e) {}
+
+    dioxus.send([stored, media.matches]);
+
+    // Keep following the system preference until the provider unmounts
+    const onChange = (event) => dioxus.send([null, event.matches]);
+    media.addEventListener('change', onChange);
+
+    await dioxus.recv();
+
+    media.removeEventListener('change', onChange);
+"#;
+
+pub const COLOR_MODE_STORE_SCRIPT: &str = r#"
+    try {
+        window.localStorage.setItem({storage_key}, {mode});
+    } catch (e) {}
+"#;
@@ -0,0 +1,47 @@
+pub const DIALOG_SCRIPT: &str = r#"
+    const dialog = document.getElementById({dialog_id});
+    const previousFocus = document.activeElement;
+    const previousOverflow = document.body.style.overflow;
+    const focusable = 'a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex="-1"])';
+
+    document.body.style.overflow = 'hidden';
+
+    function trapFocus(event) {
+        if (event.key !== 'Tab' || !dialog) {
+            return;
+        }
+
+        const elements = Array.from(dialog.querySelectorAll(focusable));
+        if (elements.length === 0) {
+            event.preventDefault();
+            dialog.focus();
+            return;
+        }
+
+        const first = elements[0];
+        const last = elements[elements.length - 1];
+        const outside = !dialog.contains(document.activeElement);
+
+        if (event.shiftKey && (document.activeElement === first || document.activeElement === dialog || outside)) {
+            event.preventDefault();
+            last.focus();
+        } else if (!event.shiftKey && (document.activeElement === last || outside)) {
+            event.preventDefault();
+            first.focus();
+        }
+    }
+
+    document.addEventListener('keydown', trapFocus);
+    if (dialog) {
+        (dialog.querySelector(focusable) || dialog).focus();
+    }
+
+    // Wait until the dialog closes or unmounts
+    await dioxus.recv();
+
+    document.removeEventListener('keydown', trapFocus);
+    document.body.style.overflow = previousOverflow;
+    if (previousFocus && previousFocus.focus) {
+        previousFocus.focus();
+    }
+"#;
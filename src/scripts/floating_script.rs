@@ -0,0 +1,28 @@
+pub const FLOATING_SCRIPT: &str = r#"
+    const anchor = document.getElementById({anchor_id});
+    const floating = document.getElementById({floating_id});
+
+    if (anchor && floating) {
+        await new Promise((resolve) => {
+            function measure() {
+                if (!anchor.isConnected || !floating.isConnected) {
+                    window.removeEventListener('scroll', measure, true);
+                    window.removeEventListener('resize', measure);
+                    resolve();
+                    return;
+                }
+
+                const a = anchor.getBoundingClientRect();
+                dioxus.send([
+                    a.left, a.top, a.width, a.height,
+                    floating.offsetWidth, floating.offsetHeight,
+                    document.documentElement.clientWidth, document.documentElement.clientHeight,
+                ]);
+            }
+
+            window.addEventListener('scroll', measure, true);
+            window.addEventListener('resize', measure);
+            measure();
+        });
+    }
+"#;
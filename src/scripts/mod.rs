@@ -1 +1,21 @@
-pub(crate) mod carousel_script;
\ No newline at end of file
+pub(crate) mod color_mode_script;
+pub(crate) mod dialog_script;
+pub(crate) mod floating_script;
+pub(crate) mod tooltip_script;
+
+use dioxus::document::{Document, Eval};
+use dioxus::prelude::try_consume_context;
+use std::rc::Rc;
+
+/// Serializes a value as a JavaScript string literal, to be inserted in the scripts.
+pub(crate) fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Runs `script` in the page of the renderer. Returns `None` when the renderer provides no document, e.g.
+/// when rendering to a string. On the server, the `Eval` fails right away: the scripts only run once the
+/// client hydrates the markup, so it must not depend on JavaScript.
+pub(crate) fn eval(script: &str) -> Option<Eval> {
+    let document = try_consume_context::<Rc<dyn Document>>()?;
+    Some(document.eval(script.to_string()))
+}
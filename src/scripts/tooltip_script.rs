@@ -0,0 +1,22 @@
+pub const TOOLTIP_SCRIPT: &str = r#"
+    const anchor = document.getElementById({anchor_id});
+    const focusable = 'a[href], button, input, select, textarea, [tabindex]:not([tabindex="-1"])';
+    const trigger = anchor && (anchor.querySelector(focusable) || anchor);
+
+    if (trigger) {
+        // Keep the descriptions the trigger already has
+        const describedBy = (trigger.getAttribute('aria-describedby') || '').split(' ').filter(Boolean);
+        trigger.setAttribute('aria-describedby', [...describedBy, {tooltip_id}].join(' '));
+
+        // Wait until the tooltip closes or unmounts
+        await dioxus.recv();
+
+        const remaining = (trigger.getAttribute('aria-describedby') || '').split(' ')
+            .filter((id) => id && id !== {tooltip_id});
+        if (remaining.length > 0) {
+            trigger.setAttribute('aria-describedby', remaining.join(' '));
+        } else {
+            trigger.removeAttribute('aria-describedby');
+        }
+    }
+"#;
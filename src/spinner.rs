@@ -1,15 +1,17 @@
 use dioxus::prelude::*;
 use crate::enums::spinner_enums::SpinnerProps;
 use crate::assets::spinner_styles::SPINNER_STYLES;
+use crate::styles::StyleOnce;
+use crate::theme::use_theme;
 
 /// Usage example:
-/// ```rust
+/// ```rust,ignore
 /// rsx! {
 ///     div {
 ///         Spinner {
 ///             height: "90".to_string(),
 ///             width: "90".to_string(),
-///             spinner_color: Some("#000".to_string()),
+///             spinner_color: "#000",
 ///             spinner_bg_color: None }
 ///     }
 // }
@@ -18,16 +20,16 @@ use crate::assets::spinner_styles::SPINNER_STYLES;
 pub fn Spinner(props: SpinnerProps) -> Element {
     let height = props.height.unwrap_or_else(|| "24px".to_string());
     let width = props.width.unwrap_or_else(|| "24px".to_string());
-    let spinner_color = props.spinner_color.unwrap_or_else(|| "#3795BD".to_string());
-    let spinner_bg_color = props.spinner_bg_color.unwrap_or_else(|| "#b4c8d1".to_string());
-    
+    let theme = use_theme();
+    let spinner_color = props.spinner_color.unwrap_or(theme.palette.primary);
+    let spinner_bg_color = props.spinner_bg_color.unwrap_or(theme.palette.primary_muted);
 
     rsx! {
-        style { "{SPINNER_STYLES}" }
+        StyleOnce { css: SPINNER_STYLES }
 
         div {
             svg {
-                class: "custom-spinner",
+                class: "freyr-custom-spinner",
                 fill: "none",
                 view_box: "0 0 64 64",
                 xmlns: "http://www.w3.org/2000/svg",
@@ -0,0 +1,116 @@
+use crate::assets::accordion_styles::{ACCORDION_NEW_STYLES, ACCORDION_STYLES};
+use crate::assets::button_style::BUTTON_STYLES;
+use crate::assets::card_styles::CARD_STYLES;
+use crate::assets::carousel_simple_styles::CAROUSEL_STYLES;
+use crate::assets::data_table_styles::DATA_TABLE_STYLES;
+use crate::assets::dialog_styles::DIALOG_STYLES;
+use crate::assets::dropdown_styles::DROPDOWN_STYLES;
+use crate::assets::floating_styles::FLOATING_STYLES;
+use crate::assets::input_styles::INPUT_STYLES;
+use crate::assets::navbar_dropdown_styles::NAVBAR_DROPDOWN_STYLES;
+use crate::assets::navbar_style::NAVBAR_STYLES;
+use crate::assets::spinner_styles::SPINNER_STYLES;
+use crate::assets::tabs_styles::{TABS_SECONDARY_STYLES, TABS_STYLES};
+use crate::assets::theme_styles::THEME_STYLES;
+use crate::assets::toast_styles::TOAST_STYLES;
+use dioxus::core::provide_root_context;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+/// Every stylesheet of the library, in the order `freyr_css` concatenates them.
+const STYLESHEETS: [&str; 17] = [
+    THEME_STYLES,
+    BUTTON_STYLES,
+    INPUT_STYLES,
+    NAVBAR_STYLES,
+    NAVBAR_DROPDOWN_STYLES,
+    DROPDOWN_STYLES,
+    CAROUSEL_STYLES,
+    TABS_STYLES,
+    TABS_SECONDARY_STYLES,
+    ACCORDION_STYLES,
+    ACCORDION_NEW_STYLES,
+    SPINNER_STYLES,
+    DIALOG_STYLES,
+    CARD_STYLES,
+    DATA_TABLE_STYLES,
+    TOAST_STYLES,
+    FLOATING_STYLES,
+];
+
+/// Stylesheets already added to the head of the document, shared by every component of a `VirtualDom`.
+#[derive(Clone, Copy)]
+struct StyleRegistry {
+    injected: CopyValue<HashSet<&'static str>>,
+    /// Whether the app links the prebuilt stylesheet with `FreyrStylesheet`, so nothing is injected.
+    external: CopyValue<bool>,
+}
+
+impl StyleRegistry {
+    fn get() -> Self {
+        try_consume_context::<StyleRegistry>().unwrap_or_else(|| {
+            provide_root_context(StyleRegistry {
+                injected: CopyValue::new_in_scope(HashSet::new(), ScopeId::ROOT),
+                external: CopyValue::new_in_scope(false, ScopeId::ROOT),
+            })
+        })
+    }
+}
+
+/// Adds `css` to the head of the document, unless another component already did or the app links the
+/// prebuilt stylesheet. The style is kept when the component is removed.
+#[component]
+pub(crate) fn StyleOnce(css: &'static str) -> Element {
+    let inject = use_hook(|| {
+        let StyleRegistry {
+            mut injected,
+            external,
+        } = StyleRegistry::get();
+        !*external.peek() && injected.write().insert(css)
+    });
+
+    rsx! {
+        if inject {
+            document::Style { "{css}" }
+        }
+    }
+}
+
+/// Returns the CSS of every component of the library, to serve it as a single prebuilt file linked
+/// with `FreyrStylesheet`.
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     std::fs::write("assets/freyr.css", freyr::freyr_css()).unwrap();
+/// }
+/// ```
+pub fn freyr_css() -> String {
+    STYLESHEETS.concat()
+}
+
+/// Links the prebuilt stylesheet returned by `freyr_css`, instead of letting each component add its own
+/// styles to the head of the document.
+///
+/// Render it at the root of the app, before any other component of the library.
+///
+/// ```rust,ignore
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         FreyrStylesheet { href: asset!("/assets/freyr.css").to_string() }
+///         Router::<Route> {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn FreyrStylesheet(href: String) -> Element {
+    use_hook(|| {
+        let StyleRegistry { mut external, .. } = StyleRegistry::get();
+        external.set(true);
+    });
+
+    rsx! {
+        document::Stylesheet { href }
+    }
+}
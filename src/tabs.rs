@@ -1,10 +1,136 @@
 use crate::assets::tabs_styles::{TABS_SECONDARY_STYLES, TABS_STYLES};
-use crate::enums::tabs_enums::{TabsColor, TabsProps};
+use crate::enums::color_enums::Color;
+use crate::enums::tabs_enums::{TabsActivation, TabsColor, TabsProps};
+use crate::styles::StyleOnce;
+use crate::unique_id::use_unique_id;
 use crate::TabsSecondaryProps;
 use dioxus::prelude::*;
+use std::rc::Rc;
 
+/// The selected tab of a tab list and its mounted tabs, to move the focus between them with the keyboard.
+#[derive(Clone, Copy)]
+struct TabList {
+    selected: Signal<usize>,
+    on_change: Option<EventHandler<usize>>,
+    disabled: CopyValue<Vec<usize>>,
+    tabs: CopyValue<Vec<Option<Rc<MountedData>>>>,
+}
+
+/// Uses the `selected` signal when given, or an internal one starting at `default_index`.
+fn use_tab_list(
+    selected: Option<Signal<usize>>,
+    default_index: Option<usize>,
+    on_change: Option<EventHandler<usize>>,
+    disabled: Vec<usize>,
+) -> TabList {
+    let internal = use_signal(|| default_index.unwrap_or(0));
+    let mut disabled_tabs = use_hook(|| CopyValue::new(Vec::new()));
+    disabled_tabs.set(disabled);
+
+    TabList {
+        selected: selected.unwrap_or(internal),
+        on_change,
+        disabled: disabled_tabs,
+        tabs: use_hook(|| CopyValue::new(Vec::new())),
+    }
+}
+
+impl TabList {
+    fn selected(&self) -> usize {
+        (self.selected)()
+    }
+
+    fn is_disabled(&self, index: usize) -> bool {
+        self.disabled.read().contains(&index)
+    }
+
+    /// Selects the tab at `index` unless it is disabled, calling `on_change` when the selection changes.
+    fn select(&mut self, index: usize) {
+        if self.is_disabled(index) || *self.selected.peek() == index {
+            return;
+        }
+        self.selected.set(index);
+        if let Some(on_change) = self.on_change {
+            on_change.call(index);
+        }
+    }
+
+    fn mount(&mut self, index: usize, tab: Rc<MountedData>) {
+        let mut tabs = self.tabs.write();
+        if tabs.len() <= index {
+            tabs.resize(index + 1, None);
+        }
+        tabs[index] = Some(tab);
+    }
+
+    fn focus(&self, index: usize) {
+        if let Some(Some(tab)) = self.tabs.peek().get(index).cloned() {
+            spawn(async move {
+                let _ = tab.set_focus(true).await;
+            });
+        }
+    }
+
+    /// Moves the focus from the tab at `index` with the arrow keys, Home and End, wrapping around
+    /// and skipping the disabled tabs, and selects the focused tab when the `activation` is automatic.
+    fn on_keydown(
+        &mut self,
+        event: KeyboardEvent,
+        index: usize,
+        count: usize,
+        activation: TabsActivation,
+    ) {
+        let enabled: Vec<usize> = (0..count).filter(|&tab| !self.is_disabled(tab)).collect();
+        let (Some(&first), Some(&last)) = (enabled.first(), enabled.last()) else {
+            return;
+        };
+
+        let next = match event.key() {
+            Key::ArrowRight => enabled
+                .iter()
+                .copied()
+                .find(|&tab| tab > index)
+                .unwrap_or(first),
+            Key::ArrowLeft => enabled
+                .iter()
+                .rev()
+                .copied()
+                .find(|&tab| tab < index)
+                .unwrap_or(last),
+            Key::Home => first,
+            Key::End => last,
+            _ => return,
+        };
+        event.prevent_default();
+        self.focus(next);
+        if activation == TabsActivation::Automatic {
+            self.select(next);
+        }
+    }
+}
+
+/// Tabs following the WAI-ARIA tabs pattern: the arrow keys, Home and End move between the tabs,
+/// selecting them unless `activation` is `TabsActivation::Manual`.
+///
+/// The selection can be controlled with a `selected` signal, e.g. to sync it with the URL, or start at `default_index`.
+/// `on_change` is called whenever the user selects another tab, and the tabs listed in `disabled` cannot be selected.
+/// ```rust,ignore
+/// let mut selected = use_signal(|| 1);
+///
+/// rsx! {
+///     TabsPrimary {
+///         tabs_names,
+///         custom_texts: Some(custom_text),
+///         selected,
+///         on_change: move |index| tracing::info!("Tab {index} selected"),
+///         disabled: vec![2],
+///     }
+///     button { onclick: move |_| selected.set(0), "Back to the first tab" }
+/// }
+/// ```
+///
 /// Tabs usage example:
-/// ```rust
+/// ```rust,ignore
 /// const IMAGE: Asset = asset!("/assets/one.jpg");
 /// #[component]
 /// pub fn Home() -> Element {
@@ -60,72 +186,82 @@ use dioxus::prelude::*;
 /// The use of tailwind is not required, but as you see, you have the option.
 #[component]
 pub fn TabsPrimary(props: TabsProps) -> Element {
-    let mut active_tab_idx = use_signal(|| 0);
-
-    let text = match &props.custom_texts {
-        Some(custom_texts) => custom_texts.get(active_tab_idx()).cloned(),
-        None => Some(rsx! {}),
-    };
+    let mut tab_list = use_tab_list(
+        props.selected,
+        props.default_index,
+        props.on_change,
+        props.disabled.clone(),
+    );
+    let active_tab_idx = tab_list.selected();
+    let tabs_id = use_unique_id("tabs");
+    let count = props.tabs_names.len();
+    let activation = props.activation;
 
-    let custom_styles = if let Some(TabsColor::Custom(color)) = &props.custom_color {
-        format!(
-            r#"
-            .custom-tab-item {{
-                color: {color};
-                border-bottom-color: transparent;
-            }}
-            .custom-tab-item:hover {{
-                color: {color};
-                border-bottom-color: {color};
-            }}
-            .custom-tab-item.active-tab {{
-                color: {color};
-                border-bottom-color: {color};
-            }}
-            "#
-        )
-    } else {
-        String::new()
+    // Set on the instance itself, so tabs of different colors can be displayed side by side
+    let custom_color = match &props.custom_color {
+        Some(TabsColor::Custom(color)) => Some(format!("--freyr-tab-custom-color: {color};")),
+        _ => None,
     };
 
     let style_tag = rsx! {
-        style {
-            "{TABS_STYLES}"
-            "{custom_styles}"
-        }
+        StyleOnce { css: TABS_STYLES }
     };
 
     rsx! {
         div {
             {style_tag}
-            div { id: "tabs", class: "tabs-container",
-                div { class: "tabs-navigation",
+            div { class: "freyr-tabs-container", style: custom_color,
+                div { class: "freyr-tabs-navigation", role: "tablist",
                     for (idx , tab_name) in props.tabs_names.iter().enumerate() {
-                        div {
+                        button {
+                            id: "{tabs_id}-tab-{idx}",
                             class: format!(
-                                "tab-item {} {} {}",
+                                "freyr-tab-item {} {} {}",
                                 if matches!(&props.custom_color, Some(TabsColor::Custom(_))) {
-                                    "custom-tab-item"
+                                    "freyr-custom-tab-item"
                                 } else {
                                     ""
                                 },
                                 props.custom_color.as_ref().map_or("", |color| color.to_css_class()),
-                                if active_tab_idx() == idx { "active-tab" } else { "" },
+                                if active_tab_idx == idx { "active-tab" } else { "" },
                             ),
-                            onclick: move |_| active_tab_idx.set(idx),
+                            r#type: "button",
+                            role: "tab",
+                            aria_selected: "{active_tab_idx == idx}",
+                            aria_controls: "{tabs_id}-panel-{idx}",
+                            tabindex: if active_tab_idx == idx { "0" } else { "-1" },
+                            disabled: tab_list.is_disabled(idx),
+                            onmounted: move |event| tab_list.mount(idx, event.data()),
+                            onclick: move |_| tab_list.select(idx),
+                            onkeydown: move |event| tab_list.on_keydown(event, idx, count, activation),
                             "{tab_name}"
                         }
                     }
                 }
 
-                div { class: "tab-content", {text} }
+                for idx in 0..count {
+                    div {
+                        id: "{tabs_id}-panel-{idx}",
+                        class: "freyr-tab-content",
+                        role: "tabpanel",
+                        aria_labelledby: "{tabs_id}-tab-{idx}",
+                        tabindex: "0",
+                        hidden: active_tab_idx != idx,
+                        if active_tab_idx == idx {
+                            {props.custom_texts.as_ref().and_then(|custom_texts| custom_texts.get(idx).cloned())}
+                        }
+                    }
+                }
             }
         }
     }
 }
 
+/// Tabs with a colored header, taking the same keyboard support, `activation`, `selected`, `default_index`,
+/// `on_change` and `disabled` props as the **`TabsPrimary`**.
+///
 /// Code implementation:
-/// ```rust
+/// ```rust,ignore
 ///     let tabs_names = vec![
 ///         String::from("Puffin"),
 ///         String::from("Otto I"),
@@ -134,94 +270,108 @@ pub fn TabsPrimary(props: TabsProps) -> Element {
 ///
 /// rsx! {
 ///     div { class: "flex flex-col items-center justify-center mt-10 w-full",
-///         // Note that you have to implement colors, width and css properties 
+///         // Note that you have to implement colors, width and css properties
 ///         // like you would do in a normal CSS project.
 ///         TabsSecondary {
 ///             tabs_names,
 ///             custom_texts: Some(custom_text),
 ///             tab_max_width: Some(String::from("500px")),
 ///             tab_bottom_radius: Some(String::from("0em 0em 1em 1em")),
-///             tab_header_hover: Some(String::from("#624b6b")),
-///             header_bg_color: Some("#bf7ed9".to_string()),
-///             header_text_color: Some("#37064a".to_string()),
-///             active_bg_color: Some("#d5b8e0".to_string()),
-///             active_text_color: Some("#2a0738".to_string()),
+///             tab_header_hover: "#624b6b",
+///             header_bg_color: "#bf7ed9",
+///             header_text_color: "#37064a",
+///             active_bg_color: "#d5b8e0",
+///             active_text_color: "#2a0738",
 ///             tab_shadow: None,
+///             activation: TabsActivation::Manual,
 ///         }
 ///     }
 /// }
 /// ```
 #[component]
 pub fn TabsSecondary(props: TabsSecondaryProps) -> Element {
+    // Set on the instance itself, so tabs styled differently can be displayed side by side
     let custom_style = format!(
-        r#"
-    :root {{
-        --tab-max-width: {};
-        --tab-header-hover: {};
-        --tab-header-bg: {};
-        --tab-header-text: {};
-        --tab-active-bg: {};
-        --tab-active-text: {};
-        --tab-radius: {};
-        --tab-shadow: {};
-    }}
-    "#,
+        "--freyr-tab-max-width: {}; --freyr-tab-header-hover: {}; --freyr-tab-header-bg: {}; --freyr-tab-header-text: {}; \
+         --freyr-tab-active-bg: {}; --freyr-tab-active-text: {}; --freyr-tab-radius: {}; --freyr-tab-shadow: {};",
         props
             .tab_max_width
             .clone()
             .unwrap_or_else(|| "700px".to_string()),
-        props
-            .tab_header_hover
-            .clone()
-            .unwrap_or_else(|| "#e5e5e5".to_string()),
-        props
-            .header_bg_color
-            .clone()
-            .unwrap_or_else(|| "#e5e5e5".to_string()),
-        props
-            .header_text_color
-            .clone()
-            .unwrap_or_else(|| "#7f7f7f".to_string()),
-        props
-            .active_bg_color
-            .clone()
-            .unwrap_or_else(|| "#ffffff".to_string()),
-        props
-            .active_text_color
-            .clone()
-            .unwrap_or_else(|| "#000000".to_string()),
+        props.tab_header_hover.as_ref().map_or_else(
+            || "var(--freyr-color-primary-muted, #B4C8D1)".to_string(),
+            Color::to_string
+        ),
+        props.header_bg_color.as_ref().map_or_else(
+            || "var(--freyr-color-border, #E5E7EB)".to_string(),
+            Color::to_string
+        ),
+        props.header_text_color.as_ref().map_or_else(
+            || "var(--freyr-color-muted, #6B7280)".to_string(),
+            Color::to_string
+        ),
+        props.active_bg_color.as_ref().map_or_else(
+            || "var(--freyr-color-surface, #FFFFFF)".to_string(),
+            Color::to_string
+        ),
+        props.active_text_color.as_ref().map_or_else(
+            || "var(--freyr-color-text, #1E201E)".to_string(),
+            Color::to_string
+        ),
         props
             .tab_radius
             .clone()
-            .unwrap_or_else(|| "1em 1em 1em 1em)".to_string()),
-        props.tab_shadow.clone().unwrap_or_else(|| "".to_string()),
+            .unwrap_or_else(|| "1em 1em 1em 1em".to_string()),
+        props.tab_shadow.clone().unwrap_or_default(),
     );
 
     let style_tag = rsx! {
-        style { "{TABS_SECONDARY_STYLES}" }
-        style { "{custom_style}" }
+        StyleOnce { css: TABS_SECONDARY_STYLES }
     };
 
     let texts = props.custom_texts.clone().unwrap_or_default();
+    let mut tab_list = use_tab_list(
+        props.selected,
+        props.default_index,
+        props.on_change,
+        props.disabled.clone(),
+    );
+    let active_tab_idx = tab_list.selected();
+    let tabs_id = use_unique_id("tabs");
+    let count = props.tabs_names.len();
+    let activation = props.activation;
+    let tabs_header_class = props.tabs_header_class.clone().unwrap_or_default();
 
     rsx! {
         div {
             {style_tag}
-            div { class: "tabs",
-                for (idx , name) in props.tabs_names.iter().enumerate() {
-                    input {
-                        r#type: "radio",
-                        class: "input",
-                        id: format!("tab-{idx}"),
-                        name: "tabs",
-                        checked: idx == 0,
-                    }
-                    label {
-                        class: "label label-default",
-                        r#for: format!("tab-{idx}"),
-                        "{name}"
+            div { class: "freyr-tabs", style: "{custom_style}",
+                div { class: "freyr-tabs-list {tabs_header_class}", role: "tablist",
+                    for (idx , name) in props.tabs_names.iter().enumerate() {
+                        button {
+                            id: "{tabs_id}-tab-{idx}",
+                            class: "freyr-label",
+                            r#type: "button",
+                            role: "tab",
+                            aria_selected: "{active_tab_idx == idx}",
+                            aria_controls: "{tabs_id}-panel-{idx}",
+                            tabindex: if active_tab_idx == idx { "0" } else { "-1" },
+                            disabled: tab_list.is_disabled(idx),
+                            onmounted: move |event| tab_list.mount(idx, event.data()),
+                            onclick: move |_| tab_list.select(idx),
+                            onkeydown: move |event| tab_list.on_keydown(event, idx, count, activation),
+                            "{name}"
+                        }
                     }
-                    div { class: "panel",
+                }
+                for idx in 0..count {
+                    div {
+                        id: "{tabs_id}-panel-{idx}",
+                        class: "freyr-panel",
+                        role: "tabpanel",
+                        aria_labelledby: "{tabs_id}-tab-{idx}",
+                        tabindex: "0",
+                        hidden: active_tab_idx != idx,
                         {texts.get(idx).cloned().unwrap_or(rsx! {
                             div {}
                         })}
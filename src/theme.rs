@@ -0,0 +1,235 @@
+use crate::assets::theme_styles::THEME_STYLES;
+use crate::enums::theme_enums::{ColorMode, Theme};
+use crate::scripts::color_mode_script::{COLOR_MODE_SCRIPT, COLOR_MODE_STORE_SCRIPT};
+use crate::scripts::{eval, js_string};
+use crate::styles::StyleOnce;
+use dioxus::document::Eval;
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy)]
+pub(crate) struct ThemeContext(Memo<Theme>);
+
+/// Handle to the color mode of the closest **`ThemeProvider`**, returned by `use_color_mode()`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ColorModeHandle {
+    mode: Signal<ColorMode>,
+    prefers_dark: Signal<bool>,
+    storage_key: CopyValue<String>,
+}
+
+impl ColorModeHandle {
+    /// The selected mode, which may be `ColorMode::System`.
+    pub fn mode(&self) -> ColorMode {
+        (self.mode)()
+    }
+
+    /// Whether the dark theme is currently displayed, resolving `ColorMode::System` with `prefers-color-scheme`.
+    pub fn is_dark(&self) -> bool {
+        match (self.mode)() {
+            ColorMode::Light => false,
+            ColorMode::Dark => true,
+            ColorMode::System => (self.prefers_dark)(),
+        }
+    }
+
+    /// Selects a mode and persists it to localStorage.
+    pub fn set(&mut self, mode: ColorMode) {
+        self.mode.set(mode);
+
+        let script = COLOR_MODE_STORE_SCRIPT
+            .replace("{storage_key}", &js_string(&self.storage_key.read()))
+            .replace("{mode}", &js_string(mode.as_str()));
+        eval(&script);
+    }
+
+    /// Switches between light and dark, starting from what is currently displayed.
+    pub fn toggle(&mut self) {
+        let mode = if self.is_dark() {
+            ColorMode::Light
+        } else {
+            ColorMode::Dark
+        };
+        self.set(mode);
+    }
+}
+
+/// Provides a `Theme` to every freyr component rendered inside it, so your brand colors are set once.
+/// Components rendered outside of a `ThemeProvider` use `Theme::default()`.
+///
+/// The provider also owns the color mode: `dark_theme` (defaults to `Theme::dark()`) is used instead of `theme`
+/// when the mode is `ColorMode::Dark`, or `ColorMode::System` and the browser prefers a dark color scheme.
+/// The selected mode is saved to localStorage under `storage_key` (defaults to `"freyr-color-mode"`).
+///
+/// ```rust,ignore
+/// #[component]
+/// fn App() -> Element {
+///     let theme = Theme {
+///         palette: Palette {
+///             primary: "#03346E".into(),
+///             ..Default::default()
+///         },
+///         ..Default::default()
+///     };
+///
+///     rsx! {
+///         ThemeProvider { theme, default_mode: ColorMode::System,
+///             Router::<Route> {}
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ThemeProvider(
+    theme: Option<Theme>,
+    dark_theme: Option<Theme>,
+    default_mode: Option<ColorMode>,
+    storage_key: Option<String>,
+    children: Element,
+) -> Element {
+    let mode = use_signal(|| default_mode.unwrap_or_default());
+    let prefers_dark = use_signal(|| false);
+    let storage_key =
+        use_hook(|| CopyValue::new(storage_key.unwrap_or_else(|| "freyr-color-mode".to_string())));
+    let color_mode = use_context_provider(|| ColorModeHandle {
+        mode,
+        prefers_dark,
+        storage_key,
+    });
+
+    let mut media_listener = use_hook(|| CopyValue::new(None::<Eval>));
+    use_future(move || async move {
+        let (mut mode, mut prefers_dark) = (mode, prefers_dark);
+        let script = COLOR_MODE_SCRIPT.replace("{storage_key}", &js_string(&storage_key.read()));
+        let Some(mut listener) = eval(&script) else {
+            return;
+        };
+        media_listener.set(Some(listener));
+
+        while let Ok((stored, dark)) = listener.recv::<(Option<String>, bool)>().await {
+            if let Some(stored) = stored.and_then(|stored| stored.parse::<ColorMode>().ok()) {
+                mode.set(stored);
+            }
+            prefers_dark.set(dark);
+        }
+    });
+
+    // Removes the `prefers-color-scheme` listener of the script
+    use_drop(move || {
+        if let Some(listener) = media_listener
+            .try_write()
+            .ok()
+            .and_then(|mut listener| listener.take())
+        {
+            let _ = listener.send(true);
+        }
+    });
+
+    let theme = use_memo(use_reactive!(|theme, dark_theme| {
+        if color_mode.is_dark() {
+            dark_theme.unwrap_or_else(Theme::dark)
+        } else {
+            theme.unwrap_or_default()
+        }
+    }));
+    use_context_provider(|| ThemeContext(theme));
+
+    let color_scheme = if color_mode.is_dark() {
+        "dark"
+    } else {
+        "light"
+    };
+
+    rsx! {
+        StyleOnce { css: THEME_STYLES }
+        div {
+            class: "freyr-theme",
+            "data-color-mode": "{color_scheme}",
+            style: "{theme.read().to_css_variables()} color-scheme: {color_scheme};",
+            {children}
+        }
+    }
+}
+
+/// Returns the `Theme` of the closest `ThemeProvider`, or `Theme::default()` when there is none.
+/// The calling component re-renders whenever the provided theme changes, e.g. when the color mode switches.
+pub fn use_theme() -> Theme {
+    match try_use_context::<ThemeContext>() {
+        Some(ThemeContext(theme)) => theme(),
+        None => Theme::default(),
+    }
+}
+
+/// Returns the color mode handle of the closest `ThemeProvider`.
+///
+/// ```rust,ignore
+/// let mut color_mode = use_color_mode();
+///
+/// rsx! {
+///     button { onclick: move |_| color_mode.set(ColorMode::System), "Use system colors" }
+/// }
+/// ```
+///
+/// **NOTE:** It panics when called outside of a `ThemeProvider`.
+pub fn use_color_mode() -> ColorModeHandle {
+    use_context::<ColorModeHandle>()
+}
+
+/// A ready-made button switching the closest `ThemeProvider` between light and dark mode.
+/// It takes the current text color, so it can be dropped directly into any navbar:
+/// ```rust,ignore
+/// rsx! {
+///     Navbar { navbar_config,
+///         ColorModeToggle {}
+///     }
+/// }
+/// ```
+#[component]
+pub fn ColorModeToggle(class: Option<String>) -> Element {
+    let mut color_mode = use_color_mode();
+    let is_dark = color_mode.is_dark();
+    let class = class.unwrap_or_default();
+
+    let label = if is_dark {
+        "Switch to light mode"
+    } else {
+        "Switch to dark mode"
+    };
+
+    rsx! {
+        button {
+            class: "freyr-color-mode-toggle {class}",
+            r#type: "button",
+            aria_label: "{label}",
+            title: "{label}",
+            onclick: move |_| color_mode.toggle(),
+            if is_dark {
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "22",
+                    height: "22",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    circle { cx: "12", cy: "12", r: "4" }
+                    path { d: "M12 2v2M12 20v2M4.93 4.93l1.41 1.41M17.66 17.66l1.41 1.41M2 12h2M20 12h2M4.93 19.07l1.41-1.41M17.66 6.34l1.41-1.41" }
+                }
+            } else {
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "22",
+                    height: "22",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    path { d: "M21 12.79A9 9 0 1 1 11.21 3 7 7 0 0 0 21 12.79z" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+/// Waits for `duration` without blocking the page, using the timers of the browser.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    let millis = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+    gloo_timers::future::TimeoutFuture::new(millis).await;
+}
+
+/// Waits for `duration` without blocking the app, using the tokio runtime of the desktop and server renderers.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
@@ -0,0 +1,219 @@
+use crate::assets::toast_styles::TOAST_STYLES;
+use crate::enums::toast_enums::{Toast, ToastId, ToastPosition, ToastVariant};
+use crate::styles::StyleOnce;
+use crate::time::sleep;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// How often the remaining time of a toast is updated.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Handle to the toasts of the closest **`ToastProvider`**, returned by `use_toast()`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseToast {
+    toasts: Signal<Vec<(ToastId, Toast)>>,
+    next_id: CopyValue<usize>,
+}
+
+impl UseToast {
+    /// Pushes a toast to the queue and returns its id.
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(*self.next_id.peek());
+        self.next_id.set(id.0 + 1);
+        self.toasts.write().push((id, toast));
+        id
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::success(message))
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::error(message))
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::info(message))
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) -> ToastId {
+        self.push(Toast::warning(message))
+    }
+
+    /// Removes a toast, whether it is displayed or still waiting in the queue.
+    pub fn dismiss(&mut self, id: ToastId) {
+        self.toasts.write().retain(|(toast_id, _)| *toast_id != id);
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.toasts.write().clear();
+    }
+}
+
+/// Displays the toasts pushed through `use_toast()` by any component rendered inside it.
+///
+/// The toasts are stacked at `position` (defaults to `ToastPosition::TopRight`) and dismissed after `duration`
+/// (defaults to 5 seconds), unless the pointer or the focus is on them. At most `max_visible` toasts (defaults to 5)
+/// are displayed at once, the next ones wait in the queue.
+///
+/// ```rust,ignore
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         ToastProvider { position: ToastPosition::BottomCenter,
+///             Router::<Route> {}
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Settings() -> Element {
+///     let mut toast = use_toast();
+///
+///     rsx! {
+///         button { onclick: move |_| { toast.success("Settings saved"); }, "Save" }
+///         button {
+///             onclick: move |_| {
+///                 toast.push(Toast::error("The file could not be deleted").persistent().action("Retry", retry));
+///             },
+///             "Delete"
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ToastProvider(
+    position: Option<ToastPosition>,
+    duration: Option<Duration>,
+    max_visible: Option<usize>,
+    children: Element,
+) -> Element {
+    let toasts = use_signal(Vec::new);
+    let next_id = use_hook(|| CopyValue::new(0));
+    let handle = use_context_provider(|| UseToast { toasts, next_id });
+
+    let position = position.unwrap_or_default();
+    let duration = duration.unwrap_or(Duration::from_secs(5));
+    let mut visible: Vec<(ToastId, Toast)> = toasts
+        .read()
+        .iter()
+        .take(max_visible.unwrap_or(5))
+        .cloned()
+        .collect();
+    // The newest toast is the closest to the edge of the screen
+    if !position.is_bottom() {
+        visible.reverse();
+    }
+
+    rsx! {
+        StyleOnce { css: TOAST_STYLES }
+
+        {children}
+        div {
+            class: "freyr-toast-container {position.as_css_class()}",
+            role: "region",
+            aria_label: "Notifications",
+            for (id , toast) in visible {
+                ToastItem { key: "{id.0}", id, toast, duration, handle }
+            }
+        }
+    }
+}
+
+/// A toast displayed by the **`ToastProvider`**, dismissing itself when its time is up.
+#[component]
+fn ToastItem(id: ToastId, toast: Toast, duration: Duration, handle: UseToast) -> Element {
+    let mut hovered = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+    let total = (!toast.persistent).then(|| toast.duration.unwrap_or(duration));
+
+    use_future(move || async move {
+        let Some(mut remaining) = total else {
+            return;
+        };
+        let mut handle = handle;
+        while !remaining.is_zero() {
+            sleep(TICK).await;
+            if !*hovered.peek() && !*focused.peek() {
+                remaining = remaining.saturating_sub(TICK);
+            }
+        }
+        handle.dismiss(id);
+    });
+
+    let Toast {
+        variant,
+        title,
+        message,
+        dismissible,
+        action,
+        ..
+    } = toast;
+    let icon = match variant {
+        ToastVariant::Success => "✓",
+        ToastVariant::Error => "✕",
+        ToastVariant::Info => "i",
+        ToastVariant::Warning => "!",
+    };
+
+    rsx! {
+        div {
+            class: "freyr-toast {variant.as_css_class()}",
+            role: variant.role(),
+            aria_atomic: "true",
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
+            onfocusin: move |_| focused.set(true),
+            onfocusout: move |_| focused.set(false),
+            span { class: "freyr-toast-icon", aria_hidden: "true", "{icon}" }
+            div { class: "freyr-toast-content",
+                if let Some(title) = title {
+                    p { class: "freyr-toast-title", "{title}" }
+                }
+                p { class: "freyr-toast-message", "{message}" }
+            }
+            if let Some((label, on_click)) = action.map(|action| (action.label, action.on_click)) {
+                button {
+                    class: "freyr-toast-action",
+                    r#type: "button",
+                    onclick: move |_| {
+                        on_click();
+                        let mut handle = handle;
+                        handle.dismiss(id);
+                    },
+                    "{label}"
+                }
+            }
+            if dismissible {
+                button {
+                    class: "freyr-toast-close",
+                    r#type: "button",
+                    aria_label: "Dismiss notification",
+                    onclick: move |_| {
+                        let mut handle = handle;
+                        handle.dismiss(id);
+                    },
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        width: "16",
+                        height: "16",
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        stroke_linecap: "round",
+                        "aria-hidden": "true",
+                        path { d: "M6 18L18 6M6 6l12 12" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the handle to the toasts of the closest `ToastProvider`.
+///
+/// **NOTE:** It panics when called outside of a `ToastProvider`.
+pub fn use_toast() -> UseToast {
+    use_context::<UseToast>()
+}
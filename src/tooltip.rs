@@ -0,0 +1,248 @@
+use crate::assets::floating_styles::FLOATING_STYLES;
+use crate::enums::floating_enums::{PopoverProps, TooltipProps};
+use crate::floating::{use_floating, UNMEASURED_STYLE};
+use crate::scripts::tooltip_script::TOOLTIP_SCRIPT;
+use crate::scripts::{eval, js_string};
+use crate::styles::StyleOnce;
+use crate::time::sleep;
+use crate::unique_id::use_unique_id;
+use dioxus::core::Task;
+use dioxus::document::Eval;
+use dioxus::prelude::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Shows a short text describing the element wrapped in it, on hover and keyboard focus.
+///
+/// The tooltip stays open while the pointer is over it, and closes on Escape. While it is open, it describes
+/// the first focusable element wrapped in it through `aria-describedby`, so screen readers announce it.
+///
+/// ```rust,ignore
+/// rsx! {
+///     Tooltip { content: "Copy to clipboard", placement: Placement::Bottom,
+///         button { onclick: move |_| copy(), "Copy" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Tooltip(props: TooltipProps) -> Element {
+    let TooltipProps {
+        content,
+        placement,
+        open_delay,
+        close_delay,
+        arrow,
+        class,
+        children,
+    } = props;
+    let tooltip_id = use_unique_id("tooltip");
+    let anchor_id = format!("{tooltip_id}-anchor");
+    let mut open = use_signal(|| false);
+    let pending = use_hook(|| CopyValue::new(None::<Task>));
+    let position = use_floating(
+        anchor_id.clone(),
+        tooltip_id.clone(),
+        open(),
+        placement,
+        8.0,
+    );
+
+    // Points the `aria-describedby` of the trigger to the tooltip while it is open
+    let mut describe_guard = use_hook(|| CopyValue::new(None::<Eval>));
+    let script_ids = (anchor_id.clone(), tooltip_id.clone());
+    use_effect(move || {
+        if open() {
+            let script = TOOLTIP_SCRIPT
+                .replace("{anchor_id}", &js_string(&script_ids.0))
+                .replace("{tooltip_id}", &js_string(&script_ids.1));
+            describe_guard.set(eval(&script));
+        } else if let Some(guard) = describe_guard.write().take() {
+            let _ = guard.send(true);
+        }
+    });
+
+    use_drop(move || {
+        if let Some(guard) = describe_guard
+            .try_write()
+            .ok()
+            .and_then(|mut guard| guard.take())
+        {
+            let _ = guard.send(true);
+        }
+    });
+
+    let open_delay = open_delay.unwrap_or(Duration::from_millis(300));
+    let close_delay = close_delay.unwrap_or(Duration::from_millis(100));
+
+    // Opens or closes the tooltip after the delay, unless it is rescheduled before
+    let schedule = move |visible: bool| {
+        let mut pending = pending;
+        if let Some(task) = pending.write().take() {
+            task.cancel();
+        }
+        if *open.peek() == visible {
+            return;
+        }
+
+        let delay = if visible { open_delay } else { close_delay };
+        pending.set(Some(spawn(async move {
+            sleep(delay).await;
+            let mut open = open;
+            open.set(visible);
+        })));
+    };
+
+    let style = position
+        .map(|position| position.style())
+        .unwrap_or_else(|| UNMEASURED_STYLE.to_string());
+    let arrow_style = position.map(|position| position.arrow_style());
+    let side = position
+        .map_or(placement, |position| position.placement)
+        .as_side_str();
+    let class = class.unwrap_or_default();
+
+    rsx! {
+        StyleOnce { css: FLOATING_STYLES }
+
+        span {
+            id: "{anchor_id}",
+            class: "freyr-tooltip-anchor",
+            onmouseenter: move |_| schedule(true),
+            onmouseleave: move |_| schedule(false),
+            onfocusin: move |_| schedule(true),
+            onfocusout: move |_| schedule(false),
+            onkeydown: move |event| {
+                if event.key() == Key::Escape && open() {
+                    schedule(false);
+                    open.set(false);
+                }
+            },
+            {children}
+        }
+        if open() {
+            div {
+                id: "{tooltip_id}",
+                class: "freyr-tooltip {class}",
+                role: "tooltip",
+                "data-placement": side,
+                style: "{style}",
+                onmouseenter: move |_| schedule(true),
+                onmouseleave: move |_| schedule(false),
+                "{content}"
+                if arrow {
+                    span { class: "freyr-floating-arrow", style: arrow_style }
+                }
+            }
+        }
+    }
+}
+
+/// A button toggling a floating panel of content anchored to it, e.g. a short form or extra details.
+///
+/// The popover takes the focus when it opens, and closes on Escape, on a click outside of it or
+/// on a second click on the button. The focus then goes back to the button.
+///
+/// ```rust,ignore
+/// rsx! {
+///     Popover {
+///         title: "Share",
+///         placement: Placement::BottomStart,
+///         content: rsx! {
+///             p { "Copy the link to this page:" }
+///             input { readonly: true, value: "https://freyr-doc.onrender.com" }
+///         },
+///         "Share"
+///     }
+/// }
+/// ```
+#[component]
+pub fn Popover(props: PopoverProps) -> Element {
+    let PopoverProps {
+        content,
+        title,
+        placement,
+        arrow,
+        trigger_class,
+        class,
+        children,
+    } = props;
+    let popover_id = use_unique_id("popover");
+    let trigger_id = format!("{popover_id}-trigger");
+    let mut open = use_signal(|| false);
+    let mut trigger = use_signal(|| None::<Rc<MountedData>>);
+    let position = use_floating(
+        trigger_id.clone(),
+        popover_id.clone(),
+        open(),
+        placement,
+        10.0,
+    );
+
+    let mut close = move || {
+        open.set(false);
+        spawn(async move {
+            if let Some(trigger) = trigger.peek().clone() {
+                let _ = trigger.set_focus(true).await;
+            }
+        });
+    };
+
+    let style = position
+        .map(|position| position.style())
+        .unwrap_or_else(|| UNMEASURED_STYLE.to_string());
+    let arrow_style = position.map(|position| position.arrow_style());
+    let side = position
+        .map_or(placement, |position| position.placement)
+        .as_side_str();
+    let trigger_class = trigger_class.unwrap_or_default();
+    let class = class.unwrap_or_default();
+
+    rsx! {
+        StyleOnce { css: FLOATING_STYLES }
+
+        if open() {
+            div { class: "freyr-popover-overlay", onclick: move |_| close() }
+        }
+        button {
+            id: "{trigger_id}",
+            class: "freyr-popover-trigger {trigger_class}",
+            r#type: "button",
+            aria_haspopup: "dialog",
+            aria_expanded: "{open}",
+            aria_controls: if open() { Some(popover_id.clone()) } else { None },
+            onmounted: move |event| trigger.set(Some(event.data())),
+            onclick: move |_| {
+                if open() {
+                    close();
+                } else {
+                    open.set(true);
+                }
+            },
+            {children}
+        }
+        if open() {
+            div {
+                id: "{popover_id}",
+                class: "freyr-popover {class}",
+                role: "dialog",
+                aria_label: title,
+                tabindex: "-1",
+                "data-placement": side,
+                style: "{style}",
+                onmounted: move |event| async move {
+                    let _ = event.set_focus(true).await;
+                },
+                onkeydown: move |event| {
+                    if event.key() == Key::Escape {
+                        event.stop_propagation();
+                        close();
+                    }
+                },
+                {content}
+                if arrow {
+                    span { class: "freyr-floating-arrow", style: arrow_style }
+                }
+            }
+        }
+    }
+}
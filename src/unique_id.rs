@@ -0,0 +1,34 @@
+use dioxus::core::provide_root_context;
+use dioxus::prelude::*;
+
+/// Counter of the ids generated by `use_unique_id`, shared by every component of a `VirtualDom`.
+#[derive(Clone, Copy)]
+struct IdCounter(CopyValue<usize>);
+
+/// Returns an id unique to the calling component, like `freyr-tabs-3`, to link labels, tabs, panels and
+/// descriptions through `for`, `aria-controls` or `aria-labelledby`.
+///
+/// The id never changes for the lifetime of the component. Ids are numbered in render order from a
+/// counter stored at the root of the app, so the server and the client generate the same ids.
+///
+/// ```rust,ignore
+/// #[component]
+/// fn Newsletter() -> Element {
+///     let id = use_unique_id("newsletter");
+///
+///     rsx! {
+///         label { r#for: "{id}", "Email" }
+///         input { id: "{id}", r#type: "email" }
+///     }
+/// }
+/// ```
+pub fn use_unique_id(prefix: &str) -> String {
+    use_hook(|| {
+        let IdCounter(mut counter) = try_consume_context::<IdCounter>().unwrap_or_else(|| {
+            provide_root_context(IdCounter(CopyValue::new_in_scope(0, ScopeId::ROOT)))
+        });
+        let id = *counter.peek();
+        counter.set(id + 1);
+        format!("freyr-{prefix}-{id}")
+    })
+}